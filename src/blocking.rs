@@ -0,0 +1,461 @@
+//! A blocking [Client], for programs without a tokio runtime, e.g. a small CLI.
+//!
+//! Every method blocks the current thread on a runtime owned by the client, errors are the
+//! same [Error] as the async client. Like `reqwest::blocking`, it must not be used from within
+//! an async runtime, that panics.
+
+use std::future::Future;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::client::{Metrics, RateLimitInfo};
+use crate::entities::*;
+use crate::error::*;
+
+pub struct Client {
+    inner: crate::client::Client,
+    runtime: tokio::runtime::Runtime,
+}
+
+impl Client {
+    /// The async client the calls are forwarded to.
+    pub fn inner(&self) -> &crate::client::Client {
+        &self.inner
+    }
+
+    /// Runs any future to completion on the client's runtime, for the async methods without a
+    /// blocking counterpart, e.g. `client.block_on(client.inner().for_each_device(..))`.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// See [crate::client::Client::last_rate_limit].
+    pub fn last_rate_limit(&self) -> Option<RateLimitInfo> {
+        self.inner.last_rate_limit()
+    }
+
+    /// See [crate::client::Client::apps_with_bundle_id_query].
+    #[deprecated(note = "use `apps` with an `AppQuery`")]
+    pub fn apps_with_bundle_id_query(
+        &self,
+        bundle_id_query: BundleIdQuery,
+    ) -> Result<PageResponse<App>> {
+        #[allow(deprecated)]
+        self.runtime
+            .block_on(self.inner.apps_with_bundle_id_query(bundle_id_query))
+    }
+
+    /// See [crate::client::Client::fetch_up_to].
+    pub fn fetch_up_to<T: for<'de> serde::Deserialize<'de>>(
+        &self,
+        first: PageResponse<T>,
+        max_items: usize,
+    ) -> Result<Vec<T>> {
+        self.runtime
+            .block_on(self.inner.fetch_up_to(first, max_items))
+    }
+
+    /// See [crate::client::Client::all_pages].
+    pub fn all_pages<T: for<'de> serde::Deserialize<'de>>(
+        &self,
+        first: PageResponse<T>,
+    ) -> Result<(Vec<T>, PagingInformation)> {
+        self.runtime.block_on(self.inner.all_pages(first))
+    }
+
+    /// See [crate::client::Client::register_new_devices].
+    pub fn register_new_devices(
+        &self,
+        devices: Vec<DeviceCreateRequestData>,
+        concurrency: usize,
+    ) -> Vec<Result<EntityResponse<Device>>> {
+        self.runtime
+            .block_on(self.inner.register_new_devices(devices, concurrency))
+    }
+}
+
+// `fn name(&self, args) -> T;` blocks on the async method of the same name
+macro_rules! blocking_methods {
+    ($(fn $name:ident(&self $(, $arg:ident: $type_id:ty)* $(,)?) -> $ret:ty;)*) => {
+        impl Client {
+            $(
+            #[doc = concat!("See [crate::client::Client::", stringify!($name), "].")]
+            pub fn $name(&self $(, $arg: $type_id)*) -> Result<$ret> {
+                self.runtime.block_on(self.inner.$name($($arg),*))
+            }
+            )*
+        }
+    };
+}
+
+blocking_methods! {
+    fn current_token(&self) -> String;
+    fn apps(&self, app_query: AppQuery) -> PageResponse<App>;
+    fn apps_raw(&self, app_query: AppQuery) -> serde_json::Value;
+    fn apps_by_url(&self, url: &str) -> PageResponse<App>;
+    fn app(&self, app_id: impl AsRef<str>, app_query: AppQuery) -> EntityResponse<App>;
+    fn search_apps_by_name(&self, substring: &str, app_query: AppQuery) -> Vec<App>;
+    fn modify_app(&self, app_id: impl AsRef<str>, request: AppUpdateRequest) -> EntityResponse<App>;
+    fn set_app_primary_locale(
+        &self,
+        app_id: impl AsRef<str>,
+        locale: impl AsRef<str>,
+    ) -> EntityResponse<App>;
+    fn available_territories(
+        &self,
+        app_id: impl AsRef<str>,
+        territory_query: TerritoryQuery,
+    ) -> PageResponse<Territory>;
+    fn set_available_territories(
+        &self,
+        app_id: impl AsRef<str>,
+        territory_ids: Vec<String>,
+        available_in_new_territories: bool,
+    ) -> ();
+    fn bundle_ids(&self, bundle_id_query: BundleIdQuery) -> PageResponse<BundleId>;
+    fn bundle_ids_raw(&self, bundle_id_query: BundleIdQuery) -> serde_json::Value;
+    fn bundle_ids_by_url(&self, url: &str) -> PageResponse<BundleId>;
+    fn register_new_bundle_id(&self, request: BundleIdCreateRequest) -> EntityResponse<BundleId>;
+    fn bundle_id(
+        &self,
+        bundle_id: impl AsRef<str>,
+        bundle_id_query: BundleIdQuery,
+    ) -> EntityResponse<BundleId>;
+    fn modify_bundle_id(
+        &self,
+        bundle_id: impl AsRef<str>,
+        request: BundleIdUpdateRequest,
+    ) -> EntityResponse<BundleId>;
+    fn delete_bundle_id(&self, bundle_id: impl AsRef<str>) -> ();
+    fn bundle_id_capabilities(
+        &self,
+        bundle_id: impl AsRef<str>,
+    ) -> BundleIdCapabilitiesWithoutIncludesResponse;
+    fn enable_capability(
+        &self,
+        request: BundleIdCapabilityCreateRequest,
+    ) -> EntityResponse<BundleIdCapability>;
+    fn disable_capability(&self, capability_id: impl AsRef<str>) -> ();
+    fn certificates(&self, certificate_query: CertificateQuery) -> PageResponse<Certificate>;
+    fn certificates_raw(&self, certificate_query: CertificateQuery) -> serde_json::Value;
+    fn certificates_by_url(&self, url: &str) -> PageResponse<Certificate>;
+    fn certificate(&self, certificate_id: impl AsRef<str>) -> EntityResponse<Certificate>;
+    fn export_certificates_pem(
+        &self,
+        certificate_query: CertificateQuery,
+    ) -> Vec<(Certificate, Result<String>)>;
+    fn revoke_certificate(&self, certificate_id: impl AsRef<str>) -> ();
+    fn profiles(&self, profile_query: ProfileQuery) -> PageResponse<Profile>;
+    fn profiles_raw(&self, profile_query: ProfileQuery) -> serde_json::Value;
+    fn profiles_by_url(&self, url: &str) -> PageResponse<Profile>;
+    fn profile(
+        &self,
+        profile_id: impl AsRef<str>,
+        profile_query: ProfileQuery,
+    ) -> EntityResponse<Profile>;
+    fn create_profile(&self, request: ProfileCreateRequest) -> EntityResponse<Profile>;
+    fn create_profile_content(&self, request: ProfileCreateRequest) -> (Profile, Vec<u8>);
+    fn delete_profile(&self, profile_id: impl AsRef<str>) -> ();
+    fn ensure_valid_profile(
+        &self,
+        name: &str,
+        profile_type: ProfileType,
+        bundle_id: impl AsRef<str>,
+        certificate_ids: Vec<String>,
+        device_ids: Vec<String>,
+        renew_before: Duration,
+    ) -> Profile;
+    fn devices(&self, device_query: DeviceQuery) -> PageResponse<Device>;
+    fn devices_raw(&self, device_query: DeviceQuery) -> serde_json::Value;
+    fn devices_by_url(&self, url: &str) -> PageResponse<Device>;
+    fn device(&self, device_id: impl AsRef<str>) -> EntityResponse<Device>;
+    fn all_devices(&self, device_query: DeviceQuery) -> Vec<Device>;
+    fn register_new_device(&self, request: DeviceCreateRequest) -> EntityResponse<Device>;
+    fn modify_device(
+        &self,
+        device_id: impl AsRef<str>,
+        request: DeviceUpdateRequest,
+    ) -> EntityResponse<Device>;
+    fn users(&self, users_query: UsersQuery) -> PageResponse<User>;
+    fn users_with_role(&self, role: Role, users_query: UsersQuery) -> PageResponse<User>;
+    fn users_raw(&self, users_query: UsersQuery) -> serde_json::Value;
+    fn users_by_url(&self, url: &str) -> PageResponse<User>;
+    fn user_information(&self, user_id: impl AsRef<str>) -> EntityResponse<User>;
+    fn modify_user(
+        &self,
+        user_id: impl AsRef<str>,
+        data: UserUpdateRequest,
+    ) -> EntityResponse<User>;
+    fn set_user_roles(&self, user_id: impl AsRef<str>, roles: Vec<Role>) -> EntityResponse<User>;
+    fn set_user_visible_apps(&self, user_id: impl AsRef<str>, app_ids: Vec<String>) -> ();
+    fn remove_user(&self, user_id: impl AsRef<str>) -> ();
+    fn user_visible_apps(
+        &self,
+        user_id: impl AsRef<str>,
+        user_visible_apps_query: UserVisibleAppsQuery,
+    ) -> PageResponse<App>;
+    fn all_user_visible_apps(
+        &self,
+        user_id: impl AsRef<str>,
+        user_visible_apps_query: UserVisibleAppsQuery,
+    ) -> Vec<App>;
+    fn invite_user(&self, data: UserInvitationCreateRequest) -> EntityResponse<UserInvitation>;
+    fn user_invitations(
+        &self,
+        user_invitation_query: UserInvitationQuery,
+    ) -> PageResponse<UserInvitation>;
+    fn user_invitations_by_url(&self, url: &str) -> PageResponse<UserInvitation>;
+    fn cancel_user_invitation(&self, user_invitation_id: impl AsRef<str>) -> ();
+    fn create_certificate(&self, request: CertificateCreateRequest) -> EntityResponse<Certificate>;
+    fn builds(&self, build_query: BuildQuery) -> PageResponse<Build>;
+    fn builds_by_url(&self, url: &str) -> PageResponse<Build>;
+    fn build(&self, build_id: impl AsRef<str>) -> EntityResponse<Build>;
+    fn build_beta_detail(&self, build_id: impl AsRef<str>) -> EntityResponse<BuildBetaDetail>;
+    fn modify_build_beta_detail(
+        &self,
+        build_beta_detail_id: impl AsRef<str>,
+        request: BuildBetaDetailUpdateRequest,
+    ) -> EntityResponse<BuildBetaDetail>;
+    fn beta_app_review_submissions(
+        &self,
+        beta_app_review_submission_query: BetaAppReviewSubmissionQuery,
+    ) -> PageResponse<BetaAppReviewSubmission>;
+    fn beta_app_review_submissions_by_url(
+        &self,
+        url: &str,
+    ) -> PageResponse<BetaAppReviewSubmission>;
+    fn create_beta_app_review_submission(
+        &self,
+        request: BetaAppReviewSubmissionCreateRequest,
+    ) -> EntityResponse<BetaAppReviewSubmission>;
+    fn builds_for_version(
+        &self,
+        pre_release_version_id: impl AsRef<str>,
+        build_query: BuildQuery,
+    ) -> PageResponse<Build>;
+    fn wait_for_build_processing(
+        &self,
+        build_id: impl AsRef<str>,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Build;
+    fn create_encryption_declaration(
+        &self,
+        request: AppEncryptionDeclarationCreateRequest,
+    ) -> EntityResponse<AppEncryptionDeclaration>;
+    fn assign_builds_to_encryption_declaration(
+        &self,
+        declaration_id: impl AsRef<str>,
+        build_ids: Vec<String>,
+    ) -> ();
+    fn submit_app_store_version(
+        &self,
+        version_id: impl AsRef<str>,
+    ) -> EntityResponse<AppStoreVersionSubmission>;
+    fn delete_app_store_version_submission(&self, submission_id: impl AsRef<str>) -> ();
+    fn app_store_versions(
+        &self,
+        app_id: impl AsRef<str>,
+        app_store_version_query: AppStoreVersionQuery,
+    ) -> PageResponse<AppStoreVersion>;
+    fn app_store_versions_by_url(&self, url: &str) -> PageResponse<AppStoreVersion>;
+    fn create_app_store_version(
+        &self,
+        request: AppStoreVersionCreateRequest,
+    ) -> EntityResponse<AppStoreVersion>;
+    fn app_store_version(&self, version_id: impl AsRef<str>) -> EntityResponse<AppStoreVersion>;
+    fn modify_app_store_version(
+        &self,
+        version_id: impl AsRef<str>,
+        request: AppStoreVersionUpdateRequest,
+    ) -> EntityResponse<AppStoreVersion>;
+    fn app_store_version_localizations(
+        &self,
+        version_id: impl AsRef<str>,
+        app_store_version_localization_query: AppStoreVersionLocalizationQuery,
+    ) -> PageResponse<AppStoreVersionLocalization>;
+    fn app_store_version_localizations_by_url(
+        &self,
+        url: &str,
+    ) -> PageResponse<AppStoreVersionLocalization>;
+    fn create_app_store_version_localization(
+        &self,
+        request: AppStoreVersionLocalizationCreateRequest,
+    ) -> EntityResponse<AppStoreVersionLocalization>;
+    fn modify_app_store_version_localization(
+        &self,
+        localization_id: impl AsRef<str>,
+        request: AppStoreVersionLocalizationUpdateRequest,
+    ) -> EntityResponse<AppStoreVersionLocalization>;
+    fn app_store_version_phased_release(
+        &self,
+        version_id: impl AsRef<str>,
+    ) -> EntityResponse<Option<AppStoreVersionPhasedRelease>>;
+    fn create_phased_release(
+        &self,
+        request: AppStoreVersionPhasedReleaseCreateRequest,
+    ) -> EntityResponse<AppStoreVersionPhasedRelease>;
+    fn modify_phased_release(
+        &self,
+        phased_release_id: impl AsRef<str>,
+        request: AppStoreVersionPhasedReleaseUpdateRequest,
+    ) -> EntityResponse<AppStoreVersionPhasedRelease>;
+    fn delete_phased_release(&self, phased_release_id: impl AsRef<str>) -> ();
+    fn app_screenshot_sets(
+        &self,
+        localization_id: impl AsRef<str>,
+        app_screenshot_set_query: AppScreenshotSetQuery,
+    ) -> PageResponse<AppScreenshotSet>;
+    fn app_screenshot_sets_by_url(&self, url: &str) -> PageResponse<AppScreenshotSet>;
+    fn create_app_screenshot_set(
+        &self,
+        request: AppScreenshotSetCreateRequest,
+    ) -> EntityResponse<AppScreenshotSet>;
+    fn create_app_screenshot(
+        &self,
+        request: AppScreenshotCreateRequest,
+    ) -> EntityResponse<AppScreenshot>;
+    fn commit_app_screenshot(
+        &self,
+        screenshot_id: impl AsRef<str>,
+        request: AppScreenshotUpdateRequest,
+    ) -> EntityResponse<AppScreenshot>;
+    fn execute_upload_operations(&self, operations: &[UploadOperation], data: &[u8]) -> ();
+    fn beta_groups(&self, beta_group_query: BetaGroupQuery) -> PageResponse<BetaGroup>;
+    fn beta_groups_by_url(&self, url: &str) -> PageResponse<BetaGroup>;
+    fn create_beta_group(&self, request: BetaGroupCreateRequest) -> EntityResponse<BetaGroup>;
+    fn delete_beta_group(&self, beta_group_id: impl AsRef<str>) -> ();
+    fn beta_testers(&self, beta_tester_query: BetaTesterQuery) -> PageResponse<BetaTester>;
+    fn beta_testers_by_url(&self, url: &str) -> PageResponse<BetaTester>;
+    fn create_beta_tester(&self, request: BetaTesterCreateRequest) -> EntityResponse<BetaTester>;
+    fn delete_beta_tester(&self, beta_tester_id: impl AsRef<str>) -> ();
+    fn add_beta_testers_to_beta_group(
+        &self,
+        beta_group_id: impl AsRef<str>,
+        beta_tester_ids: Vec<String>,
+    ) -> ();
+    fn in_app_purchases(
+        &self,
+        app_id: impl AsRef<str>,
+        in_app_purchase_query: InAppPurchaseQuery,
+    ) -> PageResponse<InAppPurchaseV2>;
+    fn in_app_purchases_by_url(&self, url: &str) -> PageResponse<InAppPurchaseV2>;
+    fn create_in_app_purchase(
+        &self,
+        request: InAppPurchaseV2CreateRequest,
+    ) -> EntityResponse<InAppPurchaseV2>;
+    fn modify_in_app_purchase(
+        &self,
+        in_app_purchase_id: impl AsRef<str>,
+        request: InAppPurchaseV2UpdateRequest,
+    ) -> EntityResponse<InAppPurchaseV2>;
+    fn delete_in_app_purchase(&self, in_app_purchase_id: impl AsRef<str>) -> ();
+    fn subscription_groups(
+        &self,
+        app_id: impl AsRef<str>,
+        subscription_group_query: SubscriptionGroupQuery,
+    ) -> PageResponse<SubscriptionGroup>;
+    fn subscription_groups_by_url(&self, url: &str) -> PageResponse<SubscriptionGroup>;
+    fn create_subscription_group(
+        &self,
+        request: SubscriptionGroupCreateRequest,
+    ) -> EntityResponse<SubscriptionGroup>;
+    fn subscriptions(
+        &self,
+        subscription_group_id: impl AsRef<str>,
+        subscription_query: SubscriptionQuery,
+    ) -> PageResponse<Subscription>;
+    fn subscriptions_by_url(&self, url: &str) -> PageResponse<Subscription>;
+    fn create_subscription(
+        &self,
+        request: SubscriptionCreateRequest,
+    ) -> EntityResponse<Subscription>;
+    fn sales_report(&self, sales_report_query: SalesReportQuery) -> Vec<u8>;
+    fn finance_report(&self, finance_report_query: FinanceReportQuery) -> Vec<u8>;
+}
+
+/// Same options as [crate::client::ClientBuilder], which can be converted with `into()`.
+#[derive(Default, Debug, Clone)]
+pub struct ClientBuilder {
+    inner: crate::client::ClientBuilder,
+}
+
+impl From<crate::client::ClientBuilder> for ClientBuilder {
+    fn from(inner: crate::client::ClientBuilder) -> Self {
+        Self { inner }
+    }
+}
+
+// `(set_name, with_name, arg: Type)` forwards both setters to the async builder
+macro_rules! builder_setters {
+    ($(($set:ident, $with:ident, $arg:ident: $type_id:ty),)*) => {
+        impl ClientBuilder {
+            $(
+            #[doc = concat!("See [crate::client::ClientBuilder::", stringify!($set), "].")]
+            pub fn $set(&mut self, $arg: $type_id) {
+                self.inner.$set($arg)
+            }
+
+            pub fn $with(mut self, $arg: $type_id) -> Self {
+                self.$set($arg);
+                self
+            }
+            )*
+        }
+    };
+}
+
+builder_setters! {
+    (set_iss, with_iss, iss: impl Into<String>),
+    (set_kid, with_kid, kid: impl Into<String>),
+    (set_ec_der, with_ec_der, ec_der: impl Into<Vec<u8>>),
+    (set_p8_pem, with_p8_pem, p8_pem: impl Into<String>),
+    (set_base_url, with_base_url, base_url: impl Into<String>),
+    (set_token_lifetime, with_token_lifetime, token_lifetime: Duration),
+    (set_clock_skew, with_clock_skew, clock_skew: Duration),
+    (set_lenient_credentials, with_lenient_credentials, lenient_credentials: bool),
+    (set_max_retries, with_max_retries, max_retries: u32),
+    (set_retry_unavailable, with_retry_unavailable, retry_unavailable: bool),
+    (set_on_retry, with_on_retry, on_retry: impl Fn(u32, &Error) + Send + Sync + 'static),
+    (set_dry_run, with_dry_run, dry_run: bool),
+    (set_metrics, with_metrics, metrics: Arc<dyn Metrics>),
+    (set_http_client, with_http_client, http_client: reqwest::Client),
+    (set_timeout, with_timeout, timeout: Duration),
+    (set_redirect_policy, with_redirect_policy, redirect_policy: reqwest::redirect::Policy),
+    (set_pool_idle_timeout, with_pool_idle_timeout, pool_idle_timeout: Duration),
+    (set_pool_max_idle_per_host, with_pool_max_idle_per_host, pool_max_idle_per_host: usize),
+    (set_http2_prior_knowledge, with_http2_prior_knowledge, http2_prior_knowledge: bool),
+    (set_user_agent, with_user_agent, user_agent: impl Into<String>),
+}
+
+impl ClientBuilder {
+    /// See [crate::client::ClientBuilder::credential_problems].
+    pub fn credential_problems(&self) -> Vec<String> {
+        self.inner.credential_problems()
+    }
+
+    /// See [crate::client::ClientBuilder::set_p8_file].
+    pub fn set_p8_file(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        self.inner.set_p8_file(path)
+    }
+
+    pub fn with_p8_file(mut self, path: impl AsRef<Path>) -> Result<Self> {
+        self.set_p8_file(path)?;
+        Ok(self)
+    }
+
+    pub fn build(self) -> Result<Client> {
+        let runtime = match tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+        {
+            Ok(runtime) => runtime,
+            Err(err) => return Err(Error::Other(Box::new(err))),
+        };
+        Ok(Client {
+            inner: self.inner.build()?,
+            runtime,
+        })
+    }
+}
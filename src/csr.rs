@@ -0,0 +1,34 @@
+use openssl::hash::MessageDigest;
+use openssl::pkey::PKey;
+use openssl::rsa::Rsa;
+use openssl::x509::{X509NameBuilder, X509ReqBuilder};
+
+use crate::error::Result;
+
+/// Generates a 2048 bit RSA key and a certificate signing request for it,
+/// like `openssl req -new` or Keychain Access do for `create_certificate`.
+/// The subject holds `common_name` and `email`, an empty `email` is left out.
+///
+/// Returns the PEM encoded request, to be sent as `csrContent`,
+/// and the PKCS#8 DER encoded private key, to be kept with the issued certificate.
+pub fn generate_csr(common_name: &str, email: &str) -> Result<(String, Vec<u8>)> {
+    let key = PKey::from_rsa(Rsa::generate(2048)?)?;
+
+    let mut name = X509NameBuilder::new()?;
+    if !email.is_empty() {
+        name.append_entry_by_text("emailAddress", email)?;
+    }
+    name.append_entry_by_text("CN", common_name)?;
+    let name = name.build();
+
+    let mut request = X509ReqBuilder::new()?;
+    request.set_version(0)?;
+    request.set_subject_name(&name)?;
+    request.set_pubkey(&key)?;
+    request.sign(&key, MessageDigest::sha256())?;
+    let request = request.build();
+
+    let pem = String::from_utf8(request.to_pem()?)
+        .map_err(|err| crate::error::Error::Other(Box::new(err)))?;
+    Ok((pem, key.private_key_to_pkcs8()?))
+}
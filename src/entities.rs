@@ -1,11 +1,15 @@
+use std::path::{Path, PathBuf};
+
+use base64::Engine;
 use chrono::{DateTime, Utc};
 use serde_derive::Deserialize;
 use serde_derive::Serialize;
 
 // common
 
+// `Variant("VALUE")`, or `Variant("VALUE", "ALIAS")` to also accept older spellings when deserializing
 macro_rules! enum_str {
-    ($name:ident { $($variant:ident($str:expr), )* }) => {
+    ($name:ident { $($variant:ident($str:expr $(, $alias:expr)*), )* }) => {
         #[derive(Clone, Copy, Debug, Eq, PartialEq)]
         pub enum $name {
             $($variant,)*
@@ -38,7 +42,7 @@ macro_rules! enum_str {
                         where E: ::serde::de::Error,
                     {
                         match value {
-                            $( $str => Ok($name::$variant), )*
+                            $( $str $(| $alias)* => Ok($name::$variant), )*
                             _ => Err(E::invalid_value(::serde::de::Unexpected::Other(
                                 &format!("unknown {} variant: {}", stringify!($name), value)
                             ), &self)),
@@ -68,33 +72,108 @@ macro_rules! format_params {
     ($variant:ident : i64) => {
         format!("{}", $variant)
     };
+    ($variant:ident : bool) => {
+        format!("{}", $variant)
+    };
     ($variant:ident : $type_id:ident) => {
         String::from($variant)
     };
 }
 
+// `name("key", Type)` is a single value param,
+// `name("key", Type, names)` is a list param joined with commas, `names` sets the whole list.
+
+macro_rules! query_param_type {
+    ($type_id:ident) => {
+        $type_id
+    };
+    ($type_id:ident, $many:ident) => {
+        Vec<$type_id>
+    };
+}
+
+macro_rules! query_param_value {
+    ($variant:ident : $type_id:ident) => {
+        format_params!($variant: $type_id)
+    };
+    ($variant:ident : $type_id:ident, $many:ident) => {
+        $variant
+            .into_iter()
+            .map(|value| format_params!(value: $type_id))
+            .collect::<Vec<String>>()
+            .join(",")
+    };
+}
+
+macro_rules! query_param_setters {
+    ($variant:ident : $type_id:ident) => {
+        pub fn $variant(mut self, $variant: $type_id) -> Self {
+            self.$variant = Some($variant);
+            self
+        }
+    };
+    ($variant:ident : $type_id:ident, $many:ident) => {
+        pub fn $variant(mut self, $variant: $type_id) -> Self {
+            self.$variant = Some(vec![$variant]);
+            self
+        }
+
+        pub fn $many(mut self, $many: Vec<$type_id>) -> Self {
+            self.$variant = Some($many);
+            self
+        }
+    };
+}
+
+// The params shared by the lists, spliced in before the others:
+// `Name: paged { ... }` adds `limit` and `cursor`,
+// `Name: paged, include { ... }` adds `include` too.
+// Sparse fieldsets (`fields[...]`) are named after each resource and stay in the lists.
+// `cursor` resumes a list where a page ended, see `PageResponse::next_cursor`, and combines with
+// `limit`: the page starting at the cursor has `limit` items.
 macro_rules! query_params {
-    ($name:ident { $($variant:ident($str:expr,$type_id:ident), )* }) => {
+    ($name:ident: paged { $($rest:tt)* }) => {
+        query_params!($name { limit("limit", i64), cursor("cursor", String), $($rest)* });
+    };
+    ($name:ident: paged, include { $($rest:tt)* }) => {
+        query_params!($name {
+            include("include", String), limit("limit", i64), cursor("cursor", String), $($rest)*
+        });
+    };
+    ($name:ident { $($variant:ident($str:expr,$type_id:ident $(,$many:ident)?), )* }) => {
         #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct $name {
-            $(pub $variant: Option<$type_id>,)*
+            $(pub $variant: Option<query_param_type!($type_id $(,$many)?)>,)*
         }
         impl $name {
             pub(crate) fn queries(self) -> Vec<(String, String)> {
                 let mut result = vec![];
                 $(
                 if let Some($variant) = self.$variant {
-                    result.push(($str.to_owned(), format_params!($variant: $type_id)));
+                    result.push(($str.to_owned(), query_param_value!($variant: $type_id $(,$many)?)));
                 }
                 )*
                 result
             }
             $(
-            pub fn $variant(mut self, $variant: $type_id) -> Self {
-                self.$variant = Some($variant);
+            query_param_setters!($variant: $type_id $(,$many)?);
+            )*
+        }
+    };
+}
+
+// Sort by id when no sort is set, pages of an unsorted list may come in an unstable order
+macro_rules! or_sort_by_id {
+    ($name:ident, $sort:ident) => {
+        impl $name {
+            // some queries only use it in the `*_stream` methods
+            #[allow(dead_code)]
+            pub(crate) fn or_sort_by_id(mut self) -> Self {
+                if self.sort.is_none() {
+                    self.sort = Some(vec![$sort::Id]);
+                }
                 self
             }
-            )*
         }
     };
 }
@@ -122,18 +201,184 @@ pub struct RelatedLinks {
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EntityResponse<T> {
     pub data: T,
+    // resources requested with `include`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub included: Vec<Included>,
     pub links: SelfLinks,
 }
 
+impl<T> EntityResponse<T> {
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+
+    pub fn as_data(&self) -> &T {
+        &self.data
+    }
+
+    /// The resource of `included` with this id, e.g. `response.find_included::<Device>(id)`.
+    pub fn find_included<R: IncludedResource>(&self, id: &str) -> Option<&R> {
+        find_included(&self.included, id)
+    }
+}
+
 // Pages
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PageResponse<T> {
     pub data: Vec<T>,
+    // resources requested with `include`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub included: Vec<Included>,
     pub links: PagedDocumentLinks,
     pub meta: PagingInformation,
 }
 
+impl<T> PageResponse<T> {
+    /// The resource of `included` with this id, e.g. `page.find_included::<Device>(id)`.
+    pub fn find_included<R: IncludedResource>(&self, id: &str) -> Option<&R> {
+        find_included(&self.included, id)
+    }
+
+    // The `cursor` param of `links.next`, `None` on the last page.
+    // Saved, it resumes the list later with the `cursor` setter of the same query.
+    pub fn next_cursor(&self) -> Option<String> {
+        let next = reqwest::Url::parse(self.links.next.as_ref()?).ok()?;
+        next.query_pairs()
+            .find(|(key, _)| key == "cursor")
+            .map(|(_, value)| value.into_owned())
+    }
+}
+
+// Relationship linkage, only present when the relationship is included
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResourceIdentifier {
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub id: String,
+}
+
+// Included resources, selected by `type`. Types not modeled yet are kept as json.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Included {
+    AppStoreVersion(AppStoreVersion),
+    BundleIdCapability(BundleIdCapability),
+    Build(Box<Build>),
+    Certificate(Box<Certificate>),
+    Device(Device),
+    PreReleaseVersion(PreReleaseVersion),
+    // boxed, certificates and profiles are much larger than the other resources
+    Profile(Box<Profile>),
+    Other(serde_json::Value),
+}
+
+/// A resource type held by a variant of [Included], see `find_included`.
+pub trait IncludedResource {
+    fn from_included(included: &Included) -> Option<&Self>;
+
+    fn resource_id(&self) -> &str;
+}
+
+macro_rules! included_resource {
+    ($($variant:ident($type_id:ident),)*) => {
+        $(
+        impl IncludedResource for $type_id {
+            fn from_included(included: &Included) -> Option<&Self> {
+                match included {
+                    // boxed variants borrow the box content
+                    Included::$variant(value) => Some(::std::borrow::Borrow::borrow(value)),
+                    _ => None,
+                }
+            }
+
+            fn resource_id(&self) -> &str {
+                &self.id
+            }
+        }
+        )*
+    };
+}
+
+included_resource! {
+    AppStoreVersion(AppStoreVersion),
+    BundleIdCapability(BundleIdCapability),
+    Build(Build),
+    Certificate(Certificate),
+    Device(Device),
+    PreReleaseVersion(PreReleaseVersion),
+    Profile(Profile),
+}
+
+fn find_included<'a, R: IncludedResource>(included: &'a [Included], id: &str) -> Option<&'a R> {
+    included
+        .iter()
+        .filter_map(R::from_included)
+        .find(|resource| resource.resource_id() == id)
+}
+
+// The resources of `included` linked by `ids`, in the order of `ids`
+fn resolve_included<'a, R: IncludedResource>(
+    included: &'a [Included],
+    ids: &Option<Vec<ResourceIdentifier>>,
+) -> Vec<&'a R> {
+    ids.iter()
+        .flatten()
+        .filter_map(|identifier| find_included(included, &identifier.id))
+        .collect()
+}
+
+impl ::serde::Serialize for Included {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        match self {
+            Included::AppStoreVersion(value) => value.serialize(serializer),
+            Included::BundleIdCapability(value) => value.serialize(serializer),
+            Included::Build(value) => value.serialize(serializer),
+            Included::Certificate(value) => value.serialize(serializer),
+            Included::Device(value) => value.serialize(serializer),
+            Included::PreReleaseVersion(value) => value.serialize(serializer),
+            Included::Profile(value) => value.serialize(serializer),
+            Included::Other(value) => value.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Included {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        use ::serde::de::Error;
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("type").and_then(|t| t.as_str()) {
+            Some("appStoreVersions") => serde_json::from_value(value)
+                .map(Included::AppStoreVersion)
+                .map_err(D::Error::custom),
+            Some("bundleIdCapabilities") => serde_json::from_value(value)
+                .map(Included::BundleIdCapability)
+                .map_err(D::Error::custom),
+            Some("builds") => serde_json::from_value(value)
+                .map(|build| Included::Build(Box::new(build)))
+                .map_err(D::Error::custom),
+            Some("certificates") => serde_json::from_value(value)
+                .map(|certificate| Included::Certificate(Box::new(certificate)))
+                .map_err(D::Error::custom),
+            Some("devices") => serde_json::from_value(value)
+                .map(Included::Device)
+                .map_err(D::Error::custom),
+            Some("preReleaseVersions") => serde_json::from_value(value)
+                .map(Included::PreReleaseVersion)
+                .map_err(D::Error::custom),
+            Some("profiles") => serde_json::from_value(value)
+                .map(|profile| Included::Profile(Box::new(profile)))
+                .map_err(D::Error::custom),
+            _ => Ok(Included::Other(value)),
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PagedDocumentLinks {
     #[serde(rename = "self")]
@@ -145,6 +390,9 @@ pub struct PagedDocumentLinks {
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PagingInformation {
     pub paging: Paging,
+    // other `meta` keys, kept as json
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -163,13 +411,23 @@ enum_str!(ContentRightsDeclaration{
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct App {
     #[serde(rename = "type")]
-    pub type_field: String,
+    pub type_field: AppsType,
     pub id: String,
     pub attributes: AppAttributes,
     pub relationships: AppRelationships,
     pub links: SelfLinks,
 }
 
+impl std::fmt::Display for App {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({})",
+            self.attributes.name, self.attributes.bundle_id
+        )
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AppAttributes {
     pub name: String,
@@ -194,6 +452,55 @@ pub struct AppAttributes {
     pub content_rights_declaration: Option<ContentRightsDeclaration>,
 }
 
+// App update
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppUpdateRequest {
+    pub data: AppUpdateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppUpdateRequestData {
+    #[serde(rename = "type")]
+    pub type_field: AppsType,
+    pub id: String,
+    pub attributes: AppUpdateRequestDataAttributes,
+}
+
+// Only the attributes set are sent, the others are left unchanged
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppUpdateRequestDataAttributes {
+    #[serde(rename = "bundleId", skip_serializing_if = "Option::is_none")]
+    pub bundle_id: Option<String>,
+    #[serde(rename = "primaryLocale", skip_serializing_if = "Option::is_none")]
+    pub primary_locale: Option<String>,
+    #[serde(
+        rename = "subscriptionStatusUrl",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub subscription_status_url: Option<String>,
+    #[serde(
+        rename = "subscriptionStatusUrlVersion",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub subscription_status_url_version: Option<String>,
+    #[serde(
+        rename = "subscriptionStatusUrlForSandbox",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub subscription_status_url_for_sandbox: Option<String>,
+    #[serde(
+        rename = "subscriptionStatusUrlVersionForSandbox",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub subscription_status_url_version_for_sandbox: Option<String>,
+    #[serde(
+        rename = "contentRightsDeclaration",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub content_rights_declaration: Option<ContentRightsDeclaration>,
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AppRelationships {
     #[serde(rename = "ciProduct")]
@@ -286,6 +593,9 @@ pub struct BetaGroups {
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AppStoreVersions {
     pub links: SelfAndRelatedLinks,
+    // with `include=appStoreVersions`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<ResourceIdentifier>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -301,6 +611,9 @@ pub struct BetaAppLocalizations {
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Builds {
     pub links: SelfAndRelatedLinks,
+    // with `include=builds`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<ResourceIdentifier>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -423,648 +736,2921 @@ pub struct AppStoreVersionExperimentsV2 {
     pub links: SelfAndRelatedLinks,
 }
 
-//
+impl PageResponse<App> {
+    // The versions of `app` found in `included`, needs `include=appStoreVersions`
+    pub fn resolve_versions(&self, app: &App) -> Vec<&AppStoreVersion> {
+        resolve_included(&self.included, &app.relationships.app_store_versions.data)
+    }
+}
 
-query_params!(BundleIdQuery{
-    fields_bundle_ids("fields[bundleIds]",String),
-    fields_profiles("fields[profiles]",String),
-    filter_id("filter[id]",String),
-    filter_identifier("filter[identifier]",String),
-    filter_name("filter[name]",String),
-    filter_platform("filter[platform]", BundleIdPlatform),
-    filter_seed_id("filter[seedId]", String),
-    include("include",String),
-    limit("limit", i64),
-    limit_profiles("limit[profiles]",i64),
-    sort("sort",BundleIdSort),
-    fields_bundle_id_capabilities("fields[bundleIdCapabilities]",String),
-    limit_bundle_id_capabilities("limit[bundleIdCapabilities]",i64),
+impl EntityResponse<App> {
+    // The versions found in `included`, needs `include=appStoreVersions`
+    pub fn resolve_versions(&self) -> Vec<&AppStoreVersion> {
+        resolve_included(
+            &self.included,
+            &self.data.relationships.app_store_versions.data,
+        )
+    }
+
+    // The builds found in `included`, needs `include=builds`
+    pub fn resolve_builds(&self) -> Vec<&Build> {
+        resolve_included(&self.included, &self.data.relationships.builds.data)
+    }
+}
+
+query_params!(AppQuery: paged, include {
     fields_apps("fields[apps]",String),
+    fields_app_store_versions("fields[appStoreVersions]",String),
+    fields_builds("fields[builds]",String),
+    limit_app_store_versions("limit[appStoreVersions]",i64),
+    limit_builds("limit[builds]",i64),
+    // lists only
+    filter_bundle_id("filter[bundleId]",String,filter_bundle_ids),
+    filter_id("filter[id]",String,filter_ids),
+    filter_name("filter[name]",String,filter_names),
+    filter_sku("filter[sku]",String,filter_skus),
+    filter_app_store_versions("filter[appStoreVersions]",String,filter_app_store_versions_any),
+    filter_app_store_versions_platform("filter[appStoreVersions.platform]",Platform,filter_app_store_versions_platforms),
+    filter_app_store_versions_app_store_state("filter[appStoreVersions.appStoreState]",AppStoreState,filter_app_store_versions_app_store_states),
+    sort("sort",AppSort,sort_many),
 });
 
-enum_str!(BundleIdSort {
-    Id("id"),
-    IdDesc("-id"),
-    Identifier("identifier"),
-    IdentifierDesc("-identifier"),
+enum_str!(AppSort {
+    BundleId("bundleId"),
+    BundleIdDesc("-bundleId"),
     Name("name"),
     NameDesc("-name"),
-    Platform("platform"),
-    PlatformDesc("-platform"),
-    SeedIdType("seedId"),
-    SeedIdDesc("-seedId"),
+    Sku("sku"),
+    SkuDesc("-sku"),
+});
+
+// The platforms of versions, builds and encryption declarations.
+// Bundle ids and devices only come as IOS or MAC_OS, see `BundleIdPlatform`.
+enum_str!(Platform {
+    Ios("IOS"),
+    MacOs("MAC_OS"),
+    TvOs("TV_OS"),
+    VisionOs("VISION_OS"),
 });
 
+// App Store Version
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct BundleId {
+pub struct AppStoreVersion {
     #[serde(rename = "type")]
-    pub type_field: BundleIdsType,
+    pub type_field: AppStoreVersionsType,
     pub id: String,
-    pub attributes: BundleIdAttributes,
-    pub relationships: BundleIdRelationships,
+    pub attributes: AppStoreVersionAttributes,
     pub links: SelfLinks,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct BundleIdAttributes {
-    pub name: String,
-    pub identifier: String,
-    pub platform: String,
-    // UNIVERSAL ?
-    #[serde(rename = "seedId")]
-    pub seed_id: String,
+pub struct AppStoreVersionAttributes {
+    pub platform: Platform,
+    #[serde(rename = "versionString")]
+    pub version_string: String,
+    #[serde(rename = "appStoreState")]
+    pub app_store_state: AppStoreState,
+    pub copyright: Option<String>,
+    #[serde(rename = "releaseType")]
+    pub release_type: Option<AppStoreVersionReleaseType>,
+    #[serde(rename = "earliestReleaseDate")]
+    pub earliest_release_date: Option<DateTime<Utc>>,
+    pub downloadable: bool,
+    #[serde(rename = "createdDate")]
+    pub created_date: DateTime<Utc>,
+}
+
+enum_str!(AppStoreVersionsType {
+    AppStoreVersions("appStoreVersions"),
+});
+
+enum_str!(AppStoreState {
+    Accepted("ACCEPTED"),
+    DeveloperRemovedFromSale("DEVELOPER_REMOVED_FROM_SALE"),
+    DeveloperRejected("DEVELOPER_REJECTED"),
+    InReview("IN_REVIEW"),
+    InvalidBinary("INVALID_BINARY"),
+    MetadataRejected("METADATA_REJECTED"),
+    PendingAppleRelease("PENDING_APPLE_RELEASE"),
+    PendingContract("PENDING_CONTRACT"),
+    PendingDeveloperRelease("PENDING_DEVELOPER_RELEASE"),
+    PrepareForSubmission("PREPARE_FOR_SUBMISSION"),
+    PreorderReadyForSale("PREORDER_READY_FOR_SALE"),
+    ProcessingForAppStore("PROCESSING_FOR_APP_STORE"),
+    ReadyForReview("READY_FOR_REVIEW"),
+    ReadyForSale("READY_FOR_SALE"),
+    Rejected("REJECTED"),
+    RemovedFromSale("REMOVED_FROM_SALE"),
+    WaitingForExportCompliance("WAITING_FOR_EXPORT_COMPLIANCE"),
+    WaitingForReview("WAITING_FOR_REVIEW"),
+    ReplacedWithNewVersion("REPLACED_WITH_NEW_VERSION"),
+    NotApplicable("NOT_APPLICABLE"),
+});
+
+enum_str!(AppStoreVersionReleaseType {
+    Manual("MANUAL"),
+    AfterApproval("AFTER_APPROVAL"),
+    Scheduled("SCHEDULED"),
+});
+
+impl AppStoreState {
+    /// Whether the version metadata can still be changed,
+    /// once submitted Apple refuses edits until the version is rejected or pulled back.
+    pub fn is_editable(&self) -> bool {
+        matches!(
+            self,
+            AppStoreState::PrepareForSubmission
+                | AppStoreState::DeveloperRejected
+                | AppStoreState::Rejected
+                | AppStoreState::MetadataRejected
+                | AppStoreState::InvalidBinary
+        )
+    }
 }
 
+query_params!(AppStoreVersionQuery: paged {
+    fields_app_store_versions("fields[appStoreVersions]", String),
+    filter_app_store_state("filter[appStoreState]", AppStoreState, filter_app_store_states),
+    filter_id("filter[id]", String, filter_ids),
+    filter_platform("filter[platform]", Platform, filter_platforms),
+    filter_version_string("filter[versionString]", String, filter_version_strings),
+});
+
+// App Store Version create
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct BundleIdRelationships {
-    #[serde(rename = "bundleIdCapabilities")]
-    pub bundle_id_capabilities: BundleIdCapabilities,
-    pub profiles: BundleIdProfiles,
+pub struct AppStoreVersionCreateRequest {
+    pub data: AppStoreVersionCreateRequestData,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct BundleIdCapabilities {
-    pub meta: PagingInformation,
-    pub links: SelfAndRelatedLinks,
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionCreateRequestData {
+    #[serde(rename = "type")]
+    pub type_field: AppStoreVersionsType,
+    pub attributes: AppStoreVersionCreateRequestDataAttributes,
+    pub relationships: AppStoreVersionCreateRequestDataRelationships,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct BundleIdProfiles {
-    pub meta: PagingInformation,
-    pub links: SelfAndRelatedLinks,
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionCreateRequestDataAttributes {
+    pub platform: Platform,
+    #[serde(rename = "versionString")]
+    pub version_string: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub copyright: Option<String>,
+    #[serde(rename = "releaseType", skip_serializing_if = "Option::is_none")]
+    pub release_type: Option<AppStoreVersionReleaseType>,
+    #[serde(
+        rename = "earliestReleaseDate",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub earliest_release_date: Option<DateTime<Utc>>,
 }
 
-enum_str!(BundleIdsType{
-    BundleIds("bundleIds"),
-});
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionCreateRequestDataRelationships {
+    pub app: AppStoreVersionCreateRequestDataRelationshipsApp,
+}
 
-//
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionCreateRequestDataRelationshipsApp {
+    pub data: AppStoreVersionCreateRequestDataRelationshipsAppData,
+}
 
-query_params!(CertificateQuery{
-    fields_certificates("fields[certificates]",String),
-    filter_id("filter[id]",String),
-    filter_serial_number("filter[serialNumber]",String),
-    limit("limit", i64),
-    sort("sort",CertificateSort),
-    filter_certificate_type("filter[certificateType]",CertificateType),
-    filter_display_name("filter[displayName]",String),
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionCreateRequestDataRelationshipsAppData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: AppsType,
+}
 
-});
+// App Store Version update
 
-enum_str!(CertificateSort {
-    Id("id"),
-    IdDesc("-id"),
-    CertificateType("certificateType"),
-    CertificateTypeDesc("-certificateType"),
-    DisplayName("displayName"),
-    DisplayNameDesc("-displayName"),
-    SerialNumber("serialNumber"),
-    SerialNumberDesc("-serialNumber"),
-});
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionUpdateRequest {
+    pub data: AppStoreVersionUpdateRequestData,
+}
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Certificate {
+pub struct AppStoreVersionUpdateRequestData {
     #[serde(rename = "type")]
-    pub type_field: CertificatesType,
+    pub type_field: AppStoreVersionsType,
     pub id: String,
-    pub attributes: CertificateAttributes,
-    pub relationships: CertificateRelationships,
-    pub links: SelfLinks,
+    pub attributes: AppStoreVersionUpdateRequestDataAttributes,
+}
+
+// Only the attributes set are sent, the others are left unchanged
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionUpdateRequestDataAttributes {
+    #[serde(rename = "versionString", skip_serializing_if = "Option::is_none")]
+    pub version_string: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub copyright: Option<String>,
+    #[serde(rename = "releaseType", skip_serializing_if = "Option::is_none")]
+    pub release_type: Option<AppStoreVersionReleaseType>,
+    #[serde(
+        rename = "earliestReleaseDate",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub earliest_release_date: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub downloadable: Option<bool>,
+}
+
+impl AppStoreVersionUpdateRequestDataAttributes {
+    // Names of the attributes that would be changed
+    pub(crate) fn changed_fields(&self) -> Vec<&'static str> {
+        let mut fields = vec![];
+        if self.version_string.is_some() {
+            fields.push("versionString");
+        }
+        if self.copyright.is_some() {
+            fields.push("copyright");
+        }
+        if self.release_type.is_some() {
+            fields.push("releaseType");
+        }
+        if self.earliest_release_date.is_some() {
+            fields.push("earliestReleaseDate");
+        }
+        if self.downloadable.is_some() {
+            fields.push("downloadable");
+        }
+        fields
+    }
 }
 
+// App Store Version Localizations, the store page text of a version in one locale
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct CertificateAttributes {
-    #[serde(rename = "serialNumber")]
-    pub serial_number: String,
-    #[serde(rename = "certificateContent")]
-    pub certificate_content: String,
-    #[serde(rename = "displayName")]
-    pub display_name: String,
-    pub name: String,
-    #[serde(rename = "csrContent")]
-    pub csr_content: serde_json::Value,
-    // null
-    pub platform: Option<String>,
-    // "IOS"/ null => IOS / MAC_OS ????
-    #[serde(rename = "expirationDate")]
-    pub expiration_date: DateTime<Utc>,
-    #[serde(rename = "certificateType")]
-    pub certificate_type: String,
+pub struct AppStoreVersionLocalization {
+    #[serde(rename = "type")]
+    pub type_field: AppStoreVersionLocalizationsType,
+    pub id: String,
+    pub attributes: AppStoreVersionLocalizationAttributes,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct CertificateRelationships {
-    #[serde(rename = "passTypeId")]
-    pub pass_type_id: CertificateRelationshipsPassTypeId,
+pub struct AppStoreVersionLocalizationAttributes {
+    pub locale: String,
+    pub description: Option<String>,
+    pub keywords: Option<String>,
+    #[serde(rename = "marketingUrl")]
+    pub marketing_url: Option<String>,
+    #[serde(rename = "promotionalText")]
+    pub promotional_text: Option<String>,
+    #[serde(rename = "supportUrl")]
+    pub support_url: Option<String>,
+    #[serde(rename = "whatsNew")]
+    pub whats_new: Option<String>,
+}
+
+enum_str!(AppStoreVersionLocalizationsType {
+    AppStoreVersionLocalizations("appStoreVersionLocalizations"),
+});
+
+query_params!(AppStoreVersionLocalizationQuery: paged {
+    fields_app_store_version_localizations("fields[appStoreVersionLocalizations]", String),
+    filter_locale("filter[locale]", String, filter_locales),
+});
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionLocalizationCreateRequest {
+    pub data: AppStoreVersionLocalizationCreateRequestData,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct CertificateRelationshipsPassTypeId {
-    pub links: SelfAndRelatedLinks,
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionLocalizationCreateRequestData {
+    #[serde(rename = "type")]
+    pub type_field: AppStoreVersionLocalizationsType,
+    pub attributes: AppStoreVersionLocalizationCreateRequestDataAttributes,
+    pub relationships: AppStoreVersionLocalizationCreateRequestDataRelationships,
 }
 
-enum_str!(CertificatesType{
-    Certificates("certificates"),
-});
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionLocalizationCreateRequestDataAttributes {
+    pub locale: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<String>,
+    #[serde(rename = "marketingUrl", skip_serializing_if = "Option::is_none")]
+    pub marketing_url: Option<String>,
+    #[serde(rename = "promotionalText", skip_serializing_if = "Option::is_none")]
+    pub promotional_text: Option<String>,
+    #[serde(rename = "supportUrl", skip_serializing_if = "Option::is_none")]
+    pub support_url: Option<String>,
+    #[serde(rename = "whatsNew", skip_serializing_if = "Option::is_none")]
+    pub whats_new: Option<String>,
+}
 
-enum_str!(CertificateType{
-    IosDevelopment("IOS_DEVELOPMENT"),
-    IosDistribution("IOS_DISTRIBUTION"),
-    MacAppDistribution("MAC_APP_DISTRIBUTION"),
-    MacInstallerDistribution("MAC_INSTALLER_DISTRIBUTION"),
-    MacAppDevelopment("MAC_APP_DEVELOPMENT"),
-    DeveloperIdKext("DEVELOPER_ID_KEXT"),
-    DeveloperIdApplication("DEVELOPER_ID_APPLICATION"),
-    Development("DEVELOPMENT"),
-    Distribution("DISTRIBUTION"),
-    PassTypeId("PASS_TYPE_ID"),
-    PassTypeIdWithNfc("PASS_TYPE_ID_WITH_NFC"),
-});
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionLocalizationCreateRequestDataRelationships {
+    #[serde(rename = "appStoreVersion")]
+    pub app_store_version: AppStoreVersionLocalizationCreateRequestDataRelationshipsAppStoreVersion,
+}
 
-// Profile
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionLocalizationCreateRequestDataRelationshipsAppStoreVersion {
+    pub data: AppStoreVersionLocalizationCreateRequestDataRelationshipsAppStoreVersionData,
+}
 
-query_params!(ProfileQuery{
-    fields_certificates("fields[certificates]",String),
-    fields_devices("fields[devices]",String),
-    filter_profiles("filter[profiles]",String),
-    filter_id("filter[id]",String),
-    filter_name("filter[name]",String),
-    include("include",String),
-    limit("limit", i64),
-    limit_certificates("limit[certificates]",i64),
-    limit_devices("limit[devices]",i64),
-    sort("sort",ProfileSort),
-    fields_bundle_ids("fields[bundleIds]",String),
-    filter_profile_state(" filter[profileState]",ProfileState),
-    filter_profile_type("filter[profileType]",ProfileType),
-});
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionLocalizationCreateRequestDataRelationshipsAppStoreVersionData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: AppStoreVersionsType,
+}
 
-enum_str!(ProfileSort{
-    Id("id"),
-    IdDesc("-id"),
-    Name("name"),
-    NameDesc("-name"),
-    ProfileState("profileState"),
-    ProfileStateDesc("-profileState"),
-    ProfileType("profileType"),
-    ProfileTypeDesc("-profileType"),
-});
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionLocalizationUpdateRequest {
+    pub data: AppStoreVersionLocalizationUpdateRequestData,
+}
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Profile {
+pub struct AppStoreVersionLocalizationUpdateRequestData {
     #[serde(rename = "type")]
-    pub type_field: ProfilesType,
+    pub type_field: AppStoreVersionLocalizationsType,
     pub id: String,
-    pub attributes: ProfileAttributes,
-    pub relationships: ProfileRelationships,
-    pub links: SelfLinks,
+    pub attributes: AppStoreVersionLocalizationUpdateRequestDataAttributes,
 }
 
+// Only the attributes set are sent, the others are left unchanged
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionLocalizationUpdateRequestDataAttributes {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<String>,
+    #[serde(rename = "marketingUrl", skip_serializing_if = "Option::is_none")]
+    pub marketing_url: Option<String>,
+    #[serde(rename = "promotionalText", skip_serializing_if = "Option::is_none")]
+    pub promotional_text: Option<String>,
+    #[serde(rename = "supportUrl", skip_serializing_if = "Option::is_none")]
+    pub support_url: Option<String>,
+    #[serde(rename = "whatsNew", skip_serializing_if = "Option::is_none")]
+    pub whats_new: Option<String>,
+}
+
+// App Store Version Phased Releases, the 7 day rollout of a version to automatic updates
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ProfileAttributes {
-    #[serde(rename = "profileState")]
-    pub profile_state: ProfileState,
-    #[serde(rename = "createdDate")]
-    pub created_date: DateTime<Utc>,
-    #[serde(rename = "profileType")]
-    pub profile_type: ProfileType,
-    pub name: String,
-    #[serde(rename = "profileContent")]
-    pub profile_content: String,
-    pub uuid: String,
-    pub platform: String,
-    #[serde(rename = "expirationDate")]
-    pub expiration_date: DateTime<Utc>,
+pub struct AppStoreVersionPhasedRelease {
+    #[serde(rename = "type")]
+    pub type_field: AppStoreVersionPhasedReleasesType,
+    pub id: String,
+    pub attributes: AppStoreVersionPhasedReleaseAttributes,
+    pub links: SelfLinks,
 }
 
-enum_str!(ProfileState{
-    INVALID("INVALID"),
-    ACTIVE("ACTIVE"),
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionPhasedReleaseAttributes {
+    #[serde(rename = "phasedReleaseState")]
+    pub phased_release_state: Option<PhasedReleaseState>,
+    // null until the version is released
+    #[serde(rename = "startDate")]
+    pub start_date: Option<DateTime<Utc>>,
+    // in days
+    #[serde(rename = "totalPauseDuration")]
+    pub total_pause_duration: Option<i64>,
+    // 1 to 7
+    #[serde(rename = "currentDayNumber")]
+    pub current_day_number: Option<i64>,
+}
+
+enum_str!(AppStoreVersionPhasedReleasesType {
+    AppStoreVersionPhasedReleases("appStoreVersionPhasedReleases"),
 });
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ProfileRelationships {
-    #[serde(rename = "bundleId")]
-    pub bundle_id: BundleIdMeta,
-    pub certificates: Certificates,
-    pub devices: Devices,
+// Set `PAUSED` to pause the rollout, `ACTIVE` to resume it, `COMPLETE` to release to everyone
+enum_str!(PhasedReleaseState {
+    Inactive("INACTIVE"),
+    Active("ACTIVE"),
+    Paused("PAUSED"),
+    Complete("COMPLETE"),
+});
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionPhasedReleaseCreateRequest {
+    pub data: AppStoreVersionPhasedReleaseCreateRequestData,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct BundleIdMeta {
-    pub links: SelfAndRelatedLinks,
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionPhasedReleaseCreateRequestData {
+    #[serde(rename = "type")]
+    pub type_field: AppStoreVersionPhasedReleasesType,
+    pub attributes: AppStoreVersionPhasedReleaseCreateRequestDataAttributes,
+    pub relationships: AppStoreVersionPhasedReleaseCreateRequestDataRelationships,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Certificates {
-    pub meta: PagingInformation,
-    pub links: SelfAndRelatedLinks,
+pub struct AppStoreVersionPhasedReleaseCreateRequestDataAttributes {
+    #[serde(rename = "phasedReleaseState", skip_serializing_if = "Option::is_none")]
+    pub phased_release_state: Option<PhasedReleaseState>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionPhasedReleaseCreateRequestDataRelationships {
+    #[serde(rename = "appStoreVersion")]
+    pub app_store_version:
+        AppStoreVersionPhasedReleaseCreateRequestDataRelationshipsAppStoreVersion,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionPhasedReleaseCreateRequestDataRelationshipsAppStoreVersion {
+    pub data: AppStoreVersionPhasedReleaseCreateRequestDataRelationshipsAppStoreVersionData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionPhasedReleaseCreateRequestDataRelationshipsAppStoreVersionData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: AppStoreVersionsType,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionPhasedReleaseUpdateRequest {
+    pub data: AppStoreVersionPhasedReleaseUpdateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionPhasedReleaseUpdateRequestData {
+    #[serde(rename = "type")]
+    pub type_field: AppStoreVersionPhasedReleasesType,
+    pub id: String,
+    pub attributes: AppStoreVersionPhasedReleaseUpdateRequestDataAttributes,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Devices {
-    pub meta: PagingInformation,
-    pub links: SelfAndRelatedLinks,
+pub struct AppStoreVersionPhasedReleaseUpdateRequestDataAttributes {
+    #[serde(rename = "phasedReleaseState", skip_serializing_if = "Option::is_none")]
+    pub phased_release_state: Option<PhasedReleaseState>,
 }
 
-enum_str!(ProfilesType{
-    Profiles("profiles"),
+// Asset uploads: reserve the asset, send its bytes as told by `upload_operations`, then commit it
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UploadOperation {
+    pub method: String,
+    pub url: String,
+    pub length: i64,
+    pub offset: i64,
+    #[serde(rename = "requestHeaders", default)]
+    pub request_headers: Vec<UploadOperationHeader>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UploadOperationHeader {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppMediaAssetState {
+    #[serde(default)]
+    pub errors: Vec<AppMediaStateError>,
+    #[serde(default)]
+    pub warnings: Vec<AppMediaStateError>,
+    pub state: AppMediaAssetStateState,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppMediaStateError {
+    pub code: String,
+    pub description: Option<String>,
+}
+
+enum_str!(AppMediaAssetStateState {
+    AwaitingUpload("AWAITING_UPLOAD"),
+    UploadComplete("UPLOAD_COMPLETE"),
+    Complete("COMPLETE"),
+    Failed("FAILED"),
 });
 
-enum_str!(ProfileType
- {
-    IosAppDevelopment("IOS_APP_DEVELOPMENT"),
-    IosAppStore("IOS_APP_STORE"),
-    IosAppAdhoc("IOS_APP_ADHOC"),
-    IosAppInhouse("IOS_APP_INHOUSE"),
-    MacAppDevelopment("MAC_APP_DEVELOPMENT"),
-    MacAppStore("MAC_APP_STORE"),
-    MacAppDirect("MAC_APP_DIRECT"),
-    TvosAppDevelopment("TVOS_APP_DEVELOPMENT"),
-    TvosAppStore("TVOS_APP_STORE"),
-    TvosAppAdhoc("TVOS_APP_ADHOC"),
-    TvosAppInhouse("TVOS_APP_INHOUSE"),
-    MacCatalystAppDevelopment("MAC_CATALYST_APP_DEVELOPMENT"),
-    MacCatalystAppStore("MAC_CATALYST_APP_STORE"),
-    MacCatalystAppDirect("MAC_CATALYST_APP_DIRECT"),
+// App Screenshot Sets, the screenshots of a localization for one display size
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppScreenshotSet {
+    #[serde(rename = "type")]
+    pub type_field: AppScreenshotSetsType,
+    pub id: String,
+    pub attributes: AppScreenshotSetAttributes,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppScreenshotSetAttributes {
+    #[serde(rename = "screenshotDisplayType")]
+    pub screenshot_display_type: ScreenshotDisplayType,
+}
+
+enum_str!(AppScreenshotSetsType {
+    AppScreenshotSets("appScreenshotSets"),
 });
 
-// profile create
+enum_str!(ScreenshotDisplayType {
+    AppIphone67("APP_IPHONE_67"),
+    AppIphone61("APP_IPHONE_61"),
+    AppIphone65("APP_IPHONE_65"),
+    AppIphone58("APP_IPHONE_58"),
+    AppIphone55("APP_IPHONE_55"),
+    AppIphone47("APP_IPHONE_47"),
+    AppIphone40("APP_IPHONE_40"),
+    AppIphone35("APP_IPHONE_35"),
+    AppIpadPro3Gen129("APP_IPAD_PRO_3GEN_129"),
+    AppIpadPro3Gen11("APP_IPAD_PRO_3GEN_11"),
+    AppIpadPro129("APP_IPAD_PRO_129"),
+    AppIpad105("APP_IPAD_105"),
+    AppIpad97("APP_IPAD_97"),
+    AppDesktop("APP_DESKTOP"),
+    AppWatchUltra("APP_WATCH_ULTRA"),
+    AppWatchSeries10("APP_WATCH_SERIES_10"),
+    AppWatchSeries7("APP_WATCH_SERIES_7"),
+    AppWatchSeries4("APP_WATCH_SERIES_4"),
+    AppWatchSeries3("APP_WATCH_SERIES_3"),
+    AppAppleTv("APP_APPLE_TV"),
+    AppAppleVisionPro("APP_APPLE_VISION_PRO"),
+    ImessageAppIphone67("IMESSAGE_APP_IPHONE_67"),
+    ImessageAppIphone61("IMESSAGE_APP_IPHONE_61"),
+    ImessageAppIphone65("IMESSAGE_APP_IPHONE_65"),
+    ImessageAppIphone58("IMESSAGE_APP_IPHONE_58"),
+    ImessageAppIphone55("IMESSAGE_APP_IPHONE_55"),
+    ImessageAppIphone47("IMESSAGE_APP_IPHONE_47"),
+    ImessageAppIphone40("IMESSAGE_APP_IPHONE_40"),
+    ImessageAppIpadPro3Gen129("IMESSAGE_APP_IPAD_PRO_3GEN_129"),
+    ImessageAppIpadPro3Gen11("IMESSAGE_APP_IPAD_PRO_3GEN_11"),
+    ImessageAppIpadPro129("IMESSAGE_APP_IPAD_PRO_129"),
+    ImessageAppIpad105("IMESSAGE_APP_IPAD_105"),
+    ImessageAppIpad97("IMESSAGE_APP_IPAD_97"),
+});
+
+query_params!(AppScreenshotSetQuery: paged {
+    fields_app_screenshot_sets("fields[appScreenshotSets]", String),
+    filter_screenshot_display_type(
+        "filter[screenshotDisplayType]",
+        ScreenshotDisplayType,
+        filter_screenshot_display_types
+    ),
+});
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ProfileCreateRequest {
-    pub data: ProfileCreateRequestData,
+pub struct AppScreenshotSetCreateRequest {
+    pub data: AppScreenshotSetCreateRequestData,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ProfileCreateRequestData {
-    pub attributes: ProfileCreateRequestAttributes,
-    pub relationships: ProfileCreateRequestRelationships,
+pub struct AppScreenshotSetCreateRequestData {
     #[serde(rename = "type")]
-    pub type_field: ProfileCreateRequestType,
+    pub type_field: AppScreenshotSetsType,
+    pub attributes: AppScreenshotSetAttributes,
+    pub relationships: AppScreenshotSetCreateRequestDataRelationships,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ProfileCreateRequestAttributes {
-    pub name: String,
-    #[serde(rename = "profileType")]
-    pub profile_type: ProfileType,
+pub struct AppScreenshotSetCreateRequestDataRelationships {
+    #[serde(rename = "appStoreVersionLocalization")]
+    pub app_store_version_localization:
+        AppScreenshotSetCreateRequestDataRelationshipsAppStoreVersionLocalization,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ProfileCreateRequestRelationships {
-    #[serde(rename = "bundleId")]
-    pub bundle_id: ProfileCreateRequestDataRelationshipsBundleId,
-    pub certificates: ProfileCreateRequestDataRelationshipsCertificates,
-    pub devices: Option<ProfileCreateRequestDataRelationshipsDevices>,
+pub struct AppScreenshotSetCreateRequestDataRelationshipsAppStoreVersionLocalization {
+    pub data: AppScreenshotSetCreateRequestDataRelationshipsAppStoreVersionLocalizationData,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ProfileCreateRequestDataRelationshipsBundleId {
-    pub data: ProfileCreateRequestDataRelationshipsBundleIdData,
+pub struct AppScreenshotSetCreateRequestDataRelationshipsAppStoreVersionLocalizationData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: AppStoreVersionLocalizationsType,
 }
 
+// App Screenshots
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ProfileCreateRequestDataRelationshipsBundleIdData {
+pub struct AppScreenshot {
+    #[serde(rename = "type")]
+    pub type_field: AppScreenshotsType,
     pub id: String,
+    pub attributes: AppScreenshotAttributes,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppScreenshotAttributes {
+    #[serde(rename = "fileName")]
+    pub file_name: String,
+    #[serde(rename = "fileSize")]
+    pub file_size: i64,
+    #[serde(rename = "sourceFileChecksum")]
+    pub source_file_checksum: Option<String>,
+    #[serde(rename = "assetToken")]
+    pub asset_token: Option<String>,
+    #[serde(rename = "assetDeliveryState")]
+    pub asset_delivery_state: Option<AppMediaAssetState>,
+    // only in the response of `create_app_screenshot`
+    #[serde(rename = "uploadOperations")]
+    pub upload_operations: Option<Vec<UploadOperation>>,
+}
+
+enum_str!(AppScreenshotsType {
+    AppScreenshots("appScreenshots"),
+});
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppScreenshotCreateRequest {
+    pub data: AppScreenshotCreateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppScreenshotCreateRequestData {
     #[serde(rename = "type")]
-    pub type_field: BundleIdsType,
+    pub type_field: AppScreenshotsType,
+    pub attributes: AppScreenshotCreateRequestDataAttributes,
+    pub relationships: AppScreenshotCreateRequestDataRelationships,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ProfileCreateRequestDataRelationshipsCertificates {
-    pub data: Vec<ProfileCreateRequestDataRelationshipsCertificatesData>,
+pub struct AppScreenshotCreateRequestDataAttributes {
+    #[serde(rename = "fileName")]
+    pub file_name: String,
+    #[serde(rename = "fileSize")]
+    pub file_size: i64,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ProfileCreateRequestDataRelationshipsCertificatesData {
+pub struct AppScreenshotCreateRequestDataRelationships {
+    #[serde(rename = "appScreenshotSet")]
+    pub app_screenshot_set: AppScreenshotCreateRequestDataRelationshipsAppScreenshotSet,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppScreenshotCreateRequestDataRelationshipsAppScreenshotSet {
+    pub data: AppScreenshotCreateRequestDataRelationshipsAppScreenshotSetData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppScreenshotCreateRequestDataRelationshipsAppScreenshotSetData {
     pub id: String,
     #[serde(rename = "type")]
-    pub type_field: CertificatesType,
+    pub type_field: AppScreenshotSetsType,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ProfileCreateRequestDataRelationshipsDevices {
-    pub data: Vec<ProfileCreateRequestDataRelationshipsDevicesData>,
+pub struct AppScreenshotUpdateRequest {
+    pub data: AppScreenshotUpdateRequestData,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ProfileCreateRequestDataRelationshipsDevicesData {
-    pub id: String,
+pub struct AppScreenshotUpdateRequestData {
     #[serde(rename = "type")]
-    pub type_field: DeviceType,
+    pub type_field: AppScreenshotsType,
+    pub id: String,
+    pub attributes: AppScreenshotUpdateRequestDataAttributes,
 }
 
-enum_str!(ProfileCreateRequestType{
-   Profiles("profiles"),
-});
+// Commit with `uploaded: true` and the MD5 of the file, hex encoded
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppScreenshotUpdateRequestDataAttributes {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uploaded: Option<bool>,
+    #[serde(rename = "sourceFileChecksum", skip_serializing_if = "Option::is_none")]
+    pub source_file_checksum: Option<String>,
+}
 
-// devices
+//
 
-query_params!(DeviceQuery{
-    fields_devices("fields[devices]",String),
-    filter_id("filter[id]",String),
+query_params!(BundleIdQuery: paged, include {
+    fields_bundle_ids("fields[bundleIds]",String),
+    fields_profiles("fields[profiles]",String),
+    filter_id("filter[id]",String,filter_ids),
+    filter_identifier("filter[identifier]",String),
     filter_name("filter[name]",String),
-    filter_platform("filter[platform]",BundleIdPlatform),
-    filter_status("filter[status]",DeviceStatus),
-    filter_udid("filter[udid]",String),
-    limit("limit", i64),
-    sort("sort", DeviceSort),
+    filter_platform("filter[platform]", BundleIdPlatform),
+    filter_seed_id("filter[seedId]", String),
+    limit_profiles("limit[profiles]",i64),
+    sort("sort",BundleIdSort,sort_many),
+    fields_bundle_id_capabilities("fields[bundleIdCapabilities]",String),
+    limit_bundle_id_capabilities("limit[bundleIdCapabilities]",i64),
 });
 
-enum_str!(DeviceSort{
+enum_str!(BundleIdSort {
     Id("id"),
     IdDesc("-id"),
+    Identifier("identifier"),
+    IdentifierDesc("-identifier"),
     Name("name"),
     NameDesc("-name"),
     Platform("platform"),
     PlatformDesc("-platform"),
-    Status("status"),
-    StatusDesc("-status"),
-    Udid("udid"),
-    UdidDesc("-udid"),
+    SeedIdType("seedId"),
+    SeedIdDesc("-seedId"),
 });
 
+or_sort_by_id!(BundleIdQuery, BundleIdSort);
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Device {
+pub struct BundleId {
     #[serde(rename = "type")]
-    pub type_field: String,
+    pub type_field: BundleIdsType,
     pub id: String,
-    pub attributes: DeviceAttributes,
+    pub attributes: BundleIdAttributes,
+    pub relationships: BundleIdRelationships,
     pub links: SelfLinks,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct DeviceAttributes {
-    #[serde(rename = "addedDate")]
-    pub added_date: DateTime<Utc>,
-    // "2022-12-10T12:02:45.000+00:00"
+pub struct BundleIdAttributes {
     pub name: String,
-    #[serde(rename = "deviceClass")]
-    pub device_class: DeviceClass,
-    pub model: Option<String>,
-    pub udid: String,
+    pub identifier: String,
     pub platform: BundleIdPlatform,
-    pub status: DeviceStatus,
+    #[serde(rename = "seedId")]
+    pub seed_id: String,
 }
 
-// Device Create
-
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct DeviceCreateRequest {
-    pub data: DeviceCreateRequestData,
-}
+pub struct BundleIdRelationships {
+    #[serde(rename = "bundleIdCapabilities")]
+    pub bundle_id_capabilities: BundleIdCapabilities,
+    pub profiles: BundleIdProfiles,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleIdCapabilities {
+    #[serde(default)]
+    pub meta: PagingInformation,
+    pub links: SelfAndRelatedLinks,
+    // with `include=bundleIdCapabilities`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<ResourceIdentifier>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleIdProfiles {
+    #[serde(default)]
+    pub meta: PagingInformation,
+    pub links: SelfAndRelatedLinks,
+    // with `include=profiles`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<ResourceIdentifier>>,
+}
+
+impl EntityResponse<BundleId> {
+    // The capabilities found in `included`, needs `include=bundleIdCapabilities`
+    pub fn resolve_capabilities(&self) -> Vec<&BundleIdCapability> {
+        resolve_included(
+            &self.included,
+            &self.data.relationships.bundle_id_capabilities.data,
+        )
+    }
+
+    // The profiles found in `included`, needs `include=profiles`
+    pub fn resolve_profiles(&self) -> Vec<&Profile> {
+        resolve_included(&self.included, &self.data.relationships.profiles.data)
+    }
+}
+
+enum_str!(BundleIdsType{
+    BundleIds("bundleIds"),
+});
+
+//
+
+query_params!(CertificateQuery: paged {
+    fields_certificates("fields[certificates]",String),
+    filter_id("filter[id]",String,filter_ids),
+    filter_serial_number("filter[serialNumber]",String),
+    sort("sort",CertificateSort,sort_many),
+    filter_certificate_type("filter[certificateType]",CertificateType),
+    filter_display_name("filter[displayName]",String),
+
+});
+
+enum_str!(CertificateSort {
+    Id("id"),
+    IdDesc("-id"),
+    CertificateType("certificateType"),
+    CertificateTypeDesc("-certificateType"),
+    DisplayName("displayName"),
+    DisplayNameDesc("-displayName"),
+    SerialNumber("serialNumber"),
+    SerialNumberDesc("-serialNumber"),
+});
+
+or_sort_by_id!(CertificateQuery, CertificateSort);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Certificate {
+    #[serde(rename = "type")]
+    pub type_field: CertificatesType,
+    pub id: String,
+    pub attributes: CertificateAttributes,
+    pub relationships: CertificateRelationships,
+    pub links: SelfLinks,
+}
+
+impl Certificate {
+    /// Writes the decoded certificate to `<name>.cer` in `dir`, replacing an existing file.
+    /// Characters not allowed in file names, like `/` or `:`, are replaced by `_`.
+    pub fn write_to_file(&self, dir: impl AsRef<Path>) -> crate::error::Result<PathBuf> {
+        write_file(
+            dir.as_ref(),
+            &self.attributes.name,
+            "cer",
+            &self.attributes.decoded_content()?,
+        )
+    }
+}
+
+impl std::fmt::Display for Certificate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({}, expires {})",
+            self.attributes.display_name,
+            self.attributes.certificate_type,
+            self.attributes.expiration_date.format("%Y-%m-%d")
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CertificateAttributes {
+    #[serde(rename = "serialNumber")]
+    pub serial_number: String,
+    #[serde(rename = "certificateContent")]
+    pub certificate_content: String,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    pub name: String,
+    #[serde(rename = "csrContent")]
+    pub csr_content: serde_json::Value,
+    // null
+    pub platform: Option<String>,
+    // "IOS"/ null => IOS / MAC_OS ????
+    #[serde(rename = "expirationDate")]
+    pub expiration_date: DateTime<Utc>,
+    #[serde(rename = "certificateType")]
+    pub certificate_type: String,
+}
+
+impl CertificateAttributes {
+    /// `certificate_content` decoded, the DER bytes of the `.cer` file.
+    pub fn decoded_content(&self) -> crate::error::Result<Vec<u8>> {
+        Ok(base64::prelude::BASE64_STANDARD.decode(self.certificate_content.as_str())?)
+    }
+
+    /// `certificate_content` as a PEM encoded certificate.
+    pub fn to_pem(&self) -> crate::error::Result<String> {
+        let der = self.decoded_content()?;
+        let body = base64::prelude::BASE64_STANDARD.encode(der);
+        let mut pem = String::from("-----BEGIN CERTIFICATE-----\n");
+        for line in body.as_bytes().chunks(64) {
+            pem.push_str(std::str::from_utf8(line).unwrap());
+            pem.push('\n');
+        }
+        pem.push_str("-----END CERTIFICATE-----\n");
+        Ok(pem)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CertificateRelationships {
+    #[serde(rename = "passTypeId")]
+    pub pass_type_id: CertificateRelationshipsPassTypeId,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CertificateRelationshipsPassTypeId {
+    pub links: SelfAndRelatedLinks,
+}
+
+enum_str!(CertificatesType{
+    Certificates("certificates"),
+});
+
+enum_str!(CertificateType{
+    IosDevelopment("IOS_DEVELOPMENT"),
+    IosDistribution("IOS_DISTRIBUTION"),
+    MacAppDistribution("MAC_APP_DISTRIBUTION"),
+    MacInstallerDistribution("MAC_INSTALLER_DISTRIBUTION"),
+    MacAppDevelopment("MAC_APP_DEVELOPMENT"),
+    DeveloperIdKext("DEVELOPER_ID_KEXT"),
+    DeveloperIdApplication("DEVELOPER_ID_APPLICATION"),
+    Development("DEVELOPMENT"),
+    Distribution("DISTRIBUTION"),
+    PassTypeId("PASS_TYPE_ID"),
+    PassTypeIdWithNfc("PASS_TYPE_ID_WITH_NFC"),
+});
+
+impl CertificateType {
+    /// All certificate types, sorted by `display_name`.
+    pub fn all() -> &'static [CertificateType] {
+        &[
+            CertificateType::Development,
+            CertificateType::Distribution,
+            CertificateType::DeveloperIdApplication,
+            CertificateType::DeveloperIdKext,
+            CertificateType::IosDevelopment,
+            CertificateType::IosDistribution,
+            CertificateType::MacAppDevelopment,
+            CertificateType::MacAppDistribution,
+            CertificateType::MacInstallerDistribution,
+            CertificateType::PassTypeId,
+            CertificateType::PassTypeIdWithNfc,
+        ]
+    }
+
+    /// Human readable label, as shown in the developer portal.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            CertificateType::IosDevelopment => "iOS Development",
+            CertificateType::IosDistribution => "iOS Distribution",
+            CertificateType::MacAppDistribution => "Mac App Distribution",
+            CertificateType::MacInstallerDistribution => "Mac Installer Distribution",
+            CertificateType::MacAppDevelopment => "Mac App Development",
+            CertificateType::DeveloperIdKext => "Developer ID Kernel Extension",
+            CertificateType::DeveloperIdApplication => "Developer ID Application",
+            CertificateType::Development => "Apple Development",
+            CertificateType::Distribution => "Apple Distribution",
+            CertificateType::PassTypeId => "Pass Type ID",
+            CertificateType::PassTypeIdWithNfc => "Pass Type ID with NFC",
+        }
+    }
+}
+
+// ordered by display name (case-insensitive)
+impl PartialOrd for CertificateType {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CertificateType {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.display_name()
+            .to_lowercase()
+            .cmp(&other.display_name().to_lowercase())
+    }
+}
+
+// Profile
+
+query_params!(ProfileQuery: paged, include {
+    fields_certificates("fields[certificates]",String),
+    fields_devices("fields[devices]",String),
+    filter_profiles("filter[profiles]",String),
+    filter_id("filter[id]",String,filter_ids),
+    filter_name("filter[name]",String),
+    limit_certificates("limit[certificates]",i64),
+    limit_devices("limit[devices]",i64),
+    sort("sort",ProfileSort,sort_many),
+    fields_bundle_ids("fields[bundleIds]",String),
+    filter_profile_state("filter[profileState]",ProfileState),
+    filter_profile_type("filter[profileType]",ProfileType,filter_profile_types),
+});
+
+impl ProfileQuery {
+    /// Only lists the profiles made for `platform`: sets `filter[profileType]` to every
+    /// profile type of that platform. `UNIVERSAL` bundle ids take any profile type, so it
+    /// sets all of them.
+    pub fn for_platform(self, platform: BundleIdPlatform) -> Self {
+        let types = ProfileType::all()
+            .iter()
+            .filter(|t| platform == BundleIdPlatform::Universal || t.platform() == platform)
+            .copied()
+            .collect();
+        self.filter_profile_types(types)
+    }
+}
+
+enum_str!(ProfileSort{
+    Id("id"),
+    IdDesc("-id"),
+    Name("name"),
+    NameDesc("-name"),
+    ProfileState("profileState"),
+    ProfileStateDesc("-profileState"),
+    ProfileType("profileType"),
+    ProfileTypeDesc("-profileType"),
+});
+
+or_sort_by_id!(ProfileQuery, ProfileSort);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    #[serde(rename = "type")]
+    pub type_field: ProfilesType,
+    pub id: String,
+    pub attributes: ProfileAttributes,
+    pub relationships: ProfileRelationships,
+    pub links: SelfLinks,
+}
+
+impl Profile {
+    /// Writes the decoded profile to `<name>.mobileprovision` in `dir`, replacing an existing file.
+    /// Characters not allowed in file names, like `/` or `:`, are replaced by `_`.
+    pub fn write_to_file(&self, dir: impl AsRef<Path>) -> crate::error::Result<PathBuf> {
+        write_file(
+            dir.as_ref(),
+            &self.attributes.name,
+            "mobileprovision",
+            &self.attributes.decoded_content()?,
+        )
+    }
+}
+
+impl std::fmt::Display for Profile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({})",
+            self.attributes.name,
+            String::from(self.attributes.profile_state)
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileAttributes {
+    #[serde(rename = "profileState")]
+    pub profile_state: ProfileState,
+    #[serde(rename = "createdDate")]
+    pub created_date: DateTime<Utc>,
+    #[serde(rename = "profileType")]
+    pub profile_type: ProfileType,
+    pub name: String,
+    #[serde(rename = "profileContent")]
+    pub profile_content: String,
+    pub uuid: String,
+    pub platform: BundleIdPlatform,
+    #[serde(rename = "expirationDate")]
+    pub expiration_date: DateTime<Utc>,
+}
+
+impl ProfileAttributes {
+    /// `profile_content` decoded, the bytes of the `.mobileprovision` file.
+    pub fn decoded_content(&self) -> crate::error::Result<Vec<u8>> {
+        Ok(base64::prelude::BASE64_STANDARD.decode(self.profile_content.as_str())?)
+    }
+}
+
+enum_str!(ProfileState{
+    INVALID("INVALID"),
+    ACTIVE("ACTIVE"),
+});
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileRelationships {
+    #[serde(rename = "bundleId")]
+    pub bundle_id: BundleIdMeta,
+    pub certificates: Certificates,
+    pub devices: Devices,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleIdMeta {
+    pub links: SelfAndRelatedLinks,
+    // with `include=bundleId`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<ResourceIdentifier>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Certificates {
+    #[serde(default)]
+    pub meta: PagingInformation,
+    pub links: SelfAndRelatedLinks,
+    // with `include=certificates`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<ResourceIdentifier>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Devices {
+    #[serde(default)]
+    pub meta: PagingInformation,
+    pub links: SelfAndRelatedLinks,
+    // with `include=devices`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<ResourceIdentifier>>,
+}
+
+impl EntityResponse<Profile> {
+    // The certificates found in `included`, needs `include=certificates`
+    pub fn resolve_certificates(&self) -> Vec<&Certificate> {
+        resolve_included(&self.included, &self.data.relationships.certificates.data)
+    }
+
+    // The devices found in `included`, needs `include=devices`
+    pub fn resolve_devices(&self) -> Vec<&Device> {
+        resolve_included(&self.included, &self.data.relationships.devices.data)
+    }
+}
+
+enum_str!(ProfilesType{
+    Profiles("profiles"),
+});
+
+enum_str!(ProfileType
+ {
+    IosAppDevelopment("IOS_APP_DEVELOPMENT"),
+    IosAppStore("IOS_APP_STORE"),
+    IosAppAdhoc("IOS_APP_ADHOC"),
+    IosAppInhouse("IOS_APP_INHOUSE"),
+    MacAppDevelopment("MAC_APP_DEVELOPMENT"),
+    MacAppStore("MAC_APP_STORE"),
+    MacAppDirect("MAC_APP_DIRECT"),
+    TvosAppDevelopment("TVOS_APP_DEVELOPMENT"),
+    TvosAppStore("TVOS_APP_STORE"),
+    TvosAppAdhoc("TVOS_APP_ADHOC"),
+    TvosAppInhouse("TVOS_APP_INHOUSE"),
+    MacCatalystAppDevelopment("MAC_CATALYST_APP_DEVELOPMENT"),
+    MacCatalystAppStore("MAC_CATALYST_APP_STORE"),
+    MacCatalystAppDirect("MAC_CATALYST_APP_DIRECT"),
+});
+
+impl ProfileType {
+    /// All profile types, sorted by `display_name`.
+    pub fn all() -> &'static [ProfileType] {
+        &[
+            ProfileType::IosAppAdhoc,
+            ProfileType::IosAppDevelopment,
+            ProfileType::IosAppStore,
+            ProfileType::IosAppInhouse,
+            ProfileType::MacCatalystAppDevelopment,
+            ProfileType::MacCatalystAppStore,
+            ProfileType::MacCatalystAppDirect,
+            ProfileType::MacAppDevelopment,
+            ProfileType::MacAppStore,
+            ProfileType::MacAppDirect,
+            ProfileType::TvosAppAdhoc,
+            ProfileType::TvosAppDevelopment,
+            ProfileType::TvosAppStore,
+            ProfileType::TvosAppInhouse,
+        ]
+    }
+
+    /// The platform of the bundle ids the profile type is made for,
+    /// tvOS and Mac Catalyst apps use iOS bundle ids.
+    pub fn platform(&self) -> BundleIdPlatform {
+        match self {
+            ProfileType::MacAppDevelopment
+            | ProfileType::MacAppStore
+            | ProfileType::MacAppDirect => BundleIdPlatform::MacOS,
+            _ => BundleIdPlatform::Ios,
+        }
+    }
+
+    /// Development and ad hoc profiles list the devices they run on,
+    /// store, in house and developer id profiles must not.
+    pub fn requires_devices(&self) -> bool {
+        matches!(
+            self,
+            ProfileType::IosAppDevelopment
+                | ProfileType::IosAppAdhoc
+                | ProfileType::MacAppDevelopment
+                | ProfileType::TvosAppDevelopment
+                | ProfileType::TvosAppAdhoc
+                | ProfileType::MacCatalystAppDevelopment
+        )
+    }
+
+    /// Human readable label, as shown in the developer portal.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ProfileType::IosAppDevelopment => "iOS App Development",
+            ProfileType::IosAppStore => "iOS App Store",
+            ProfileType::IosAppAdhoc => "iOS Ad Hoc",
+            ProfileType::IosAppInhouse => "iOS In House",
+            ProfileType::MacAppDevelopment => "macOS App Development",
+            ProfileType::MacAppStore => "macOS App Store",
+            ProfileType::MacAppDirect => "macOS Developer ID",
+            ProfileType::TvosAppDevelopment => "tvOS App Development",
+            ProfileType::TvosAppStore => "tvOS App Store",
+            ProfileType::TvosAppAdhoc => "tvOS Ad Hoc",
+            ProfileType::TvosAppInhouse => "tvOS In House",
+            ProfileType::MacCatalystAppDevelopment => "Mac Catalyst App Development",
+            ProfileType::MacCatalystAppStore => "Mac Catalyst App Store",
+            ProfileType::MacCatalystAppDirect => "Mac Catalyst Developer ID",
+        }
+    }
+}
+
+// ordered by display name (case-insensitive)
+impl PartialOrd for ProfileType {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ProfileType {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.display_name()
+            .to_lowercase()
+            .cmp(&other.display_name().to_lowercase())
+    }
+}
+
+// profile create
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileCreateRequest {
+    pub data: ProfileCreateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileCreateRequestData {
+    pub attributes: ProfileCreateRequestAttributes,
+    pub relationships: ProfileCreateRequestRelationships,
+    #[serde(rename = "type")]
+    pub type_field: ProfileCreateRequestType,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileCreateRequestAttributes {
+    pub name: String,
+    #[serde(rename = "profileType")]
+    pub profile_type: ProfileType,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileCreateRequestRelationships {
+    #[serde(rename = "bundleId")]
+    pub bundle_id: ProfileCreateRequestDataRelationshipsBundleId,
+    pub certificates: ProfileCreateRequestDataRelationshipsCertificates,
+    pub devices: Option<ProfileCreateRequestDataRelationshipsDevices>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileCreateRequestDataRelationshipsBundleId {
+    pub data: ProfileCreateRequestDataRelationshipsBundleIdData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileCreateRequestDataRelationshipsBundleIdData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: BundleIdsType,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileCreateRequestDataRelationshipsCertificates {
+    pub data: Vec<ProfileCreateRequestDataRelationshipsCertificatesData>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileCreateRequestDataRelationshipsCertificatesData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: CertificatesType,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileCreateRequestDataRelationshipsDevices {
+    pub data: Vec<ProfileCreateRequestDataRelationshipsDevicesData>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileCreateRequestDataRelationshipsDevicesData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: DeviceType,
+}
+
+impl ProfileCreateRequest {
+    // Checks the devices against the profile type, Apple's error for a mismatch is opaque
+    pub(crate) fn validate(&self) -> crate::error::Result<()> {
+        let profile_type = self.data.attributes.profile_type;
+        let devices = self
+            .data
+            .relationships
+            .devices
+            .as_ref()
+            .map(|devices| devices.data.len())
+            .unwrap_or_default();
+        if profile_type.requires_devices() && devices == 0 {
+            return Err(crate::error::Error::message(format!(
+                "{} profiles require at least one device",
+                String::from(profile_type)
+            )));
+        }
+        if !profile_type.requires_devices() && devices > 0 {
+            return Err(crate::error::Error::message(format!(
+                "{} profiles must not list devices",
+                String::from(profile_type)
+            )));
+        }
+        Ok(())
+    }
+}
+
+enum_str!(ProfileCreateRequestType{
+   Profiles("profiles"),
+});
+
+// devices
+
+query_params!(DeviceQuery: paged {
+    fields_devices("fields[devices]",String),
+    filter_id("filter[id]",String,filter_ids),
+    filter_name("filter[name]",String),
+    filter_platform("filter[platform]",BundleIdPlatform),
+    filter_status("filter[status]",DeviceStatus),
+    filter_udid("filter[udid]",String),
+    sort("sort",DeviceSort,sort_many),
+});
+
+impl DeviceQuery {
+    /// Only lists the devices that can be added to profiles: sets `filter[status]=ENABLED`.
+    pub fn enabled_only(self) -> Self {
+        self.filter_status(DeviceStatus::Enabled)
+    }
+}
+
+enum_str!(DeviceSort{
+    Id("id"),
+    IdDesc("-id"),
+    Name("name"),
+    NameDesc("-name"),
+    Platform("platform"),
+    PlatformDesc("-platform"),
+    Status("status"),
+    StatusDesc("-status"),
+    Udid("udid"),
+    UdidDesc("-udid"),
+});
+
+or_sort_by_id!(DeviceQuery, DeviceSort);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Device {
+    #[serde(rename = "type")]
+    pub type_field: DeviceType,
+    pub id: String,
+    pub attributes: DeviceAttributes,
+    pub links: SelfLinks,
+}
+
+impl std::fmt::Display for Device {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.attributes.name, self.attributes.udid)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeviceAttributes {
+    #[serde(rename = "addedDate")]
+    pub added_date: DateTime<Utc>,
+    // "2022-12-10T12:02:45.000+00:00"
+    pub name: String,
+    #[serde(rename = "deviceClass")]
+    pub device_class: DeviceClass,
+    pub model: Option<String>,
+    pub udid: String,
+    pub platform: BundleIdPlatform,
+    pub status: DeviceStatus,
+}
+
+// Device Create
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeviceCreateRequest {
+    pub data: DeviceCreateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeviceCreateRequestData {
+    #[serde(rename = "type")]
+    pub type_field: DeviceType,
+    pub attributes: DeviceCreateRequestDataAttributes,
+}
+
+enum_str!(DeviceType {
+    Devices("devices"),
+});
+
+enum_str!(DeviceStatus {
+    Enabled("ENABLED"),
+    Disabled("DISABLED"),
+    Processing("PROCESSING"),
+    Ineligible("INELIGIBLE"),
+});
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeviceCreateRequestDataAttributes {
+    pub name: String,
+    pub platform: BundleIdPlatform,
+    pub udid: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeviceUpdateRequest {
+    pub data: DeviceUpdateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeviceUpdateRequestData {
+    #[serde(rename = "type")]
+    pub type_field: DeviceType,
+    pub id: String,
+    pub attributes: DeviceUpdateRequestDataAttributes,
+}
+
+// Only the attributes set are sent. Devices can't be deleted, a `DISABLED` one frees its slot
+// at the start of the next membership year.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeviceUpdateRequestDataAttributes {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<DeviceStatus>,
+}
+
+enum_str!(DeviceClass {
+    AppleWatch("APPLE_WATCH"),
+    Ipad("IPAD"),
+    Iphone("IPHONE"),
+    Ipod("IPOD"),
+    AppleTv("APPLE_TV"),
+    Mac("MAC"),
+});
+
+enum_str!(BundleIdPlatform {
+    Ios("IOS"),
+    // some endpoints answer `MACOS`
+    MacOS("MAC_OS", "MACOS"),
+    // bundle ids and profiles shared by iOS and macOS apps
+    Universal("UNIVERSAL"),
+});
+
+//
+
+query_params!(UsersQuery: paged, include {
+    fields_apps("fields[apps]",String),
+    fields_users("fields[users]",String),
+    sort("sort",UserSort,sort_many),
+    filter_roles("filter[roles]",Role,filter_roles_any),
+    filter_visible_apps("filter[visibleApps]",String),
+    filter_username("filter[username]",String),
+    limit_visible_apps("limit[visibleApps]",i64),
+});
+
+query_params!(UserVisibleAppsQuery: paged {
+    fields_apps("fields[apps]",String),
+});
+
+enum_str!(UserSort{
+    LastName("lastName"),
+    LastNameDesc("-lastName"),
+    Username("username"),
+    UsernameDesc("-username"),
+});
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct User {
+    #[serde(rename = "type")]
+    pub type_field: UserType,
+    pub id: String,
+    pub attributes: UserAttributes,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserAttributes {
+    pub username: String,
+    #[serde(rename = "firstName")]
+    pub first_name: String,
+    #[serde(rename = "lastName")]
+    pub last_name: String,
+    pub roles: Vec<Role>,
+    #[serde(rename = "allAppsVisible")]
+    pub all_apps_visible: bool,
+    #[serde(rename = "provisioningAllowed")]
+    pub provisioning_allowed: bool,
+}
+
+enum_str!(UserType{
+    Users("users"),
+});
+
+enum_str!(Role{
+    Admin("ADMIN"),
+    Finance("FINANCE"),
+    AccountHolder("ACCOUNT_HOLDER"),
+    Sales("SALES"),
+    Marketing("MARKETING"),
+    AppManager("APP_MANAGER"),
+    Developer("DEVELOPER"),
+    AccessToReports("ACCESS_TO_REPORTS"),
+    CustomerSupport("CUSTOMER_SUPPORT"),
+    ImageManager("IMAGE_MANAGER"),
+    CreateApps("CREATE_APPS"),
+    CloudManagedDeveloperId("CLOUD_MANAGED_DEVELOPER_ID"),
+    CloudManagedAppDistribution("CLOUD_MANAGED_APP_DISTRIBUTION"),
+});
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserUpdateRequest {
+    pub data: UserUpdateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserUpdateRequestData {
+    #[serde(rename = "type")]
+    pub type_field: UserType,
+    pub id: String,
+    pub attributes: UserUpdateRequestDataAttributes,
+    pub relationships: UserUpdateRequestDataRelationships,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserUpdateRequestDataAttributes {
+    pub roles: Vec<Role>,
+    #[serde(rename = "allAppsVisible")]
+    pub all_apps_visible: bool,
+    #[serde(rename = "provisioningAllowed")]
+    pub provisioning_allowed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserUpdateRequestDataRelationships {
+    #[serde(rename = "visibleApps")]
+    pub visible_apps: UserUpdateRequestDataRelationshipsVisibleApps,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserUpdateRequestDataRelationshipsVisibleApps {
+    pub data: Vec<UserUpdateRequestDataRelationshipsVisibleAppsData>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserUpdateRequestDataRelationshipsVisibleAppsData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: AppsType,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserRolesUpdateRequest {
+    pub data: UserRolesUpdateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserRolesUpdateRequestData {
+    #[serde(rename = "type")]
+    pub type_field: UserType,
+    pub id: String,
+    pub attributes: UserRolesUpdateRequestDataAttributes,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserRolesUpdateRequestDataAttributes {
+    pub roles: Vec<Role>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserVisibleAppsLinkagesRequest {
+    pub data: Vec<UserUpdateRequestDataRelationshipsVisibleAppsData>,
+}
+
+//
+
+query_params!(UserInvitationQuery: paged, include {
+    fields_apps("fields[apps]",String),
+    fields_user_invitations("fields[userInvitations]",String),
+    sort("sort",UserInvitationSort,sort_many),
+    filter_email("filter[email]",String),
+    filter_roles("filter[roles]",Role,filter_roles_any),
+    filter_visible_apps("filter[visibleApps]",String),
+    limit_visible_apps("limit[visibleApps]",i64),
+});
+
+enum_str!(UserInvitationSort{
+    Email("email"),
+    EmailDesc("-email"),
+    LastName("lastName"),
+    LastNameDesc("-lastName"),
+});
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserInvitation {
+    #[serde(rename = "type")]
+    pub type_field: UserInvitationsType,
+    pub id: String,
+    pub attributes: UserInvitationAttributes,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserInvitationAttributes {
+    pub email: String,
+    #[serde(rename = "firstName")]
+    pub first_name: String,
+    #[serde(rename = "lastName")]
+    pub last_name: String,
+    pub roles: Vec<Role>,
+    #[serde(rename = "allAppsVisible")]
+    pub all_apps_visible: bool,
+    #[serde(rename = "provisioningAllowed")]
+    pub provisioning_allowed: bool,
+    #[serde(rename = "expirationDate")]
+    pub expiration_date: DateTime<Utc>,
+}
+
+enum_str!(UserInvitationsType{
+    UserInvitations("userInvitations"),
+});
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserInvitationCreateRequest {
+    pub data: UserInvitationCreateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserInvitationCreateRequestData {
+    #[serde(rename = "type")]
+    pub type_field: UserInvitationsType,
+    pub attributes: UserInvitationCreateRequestDataAttributes,
+    // only needed when `all_apps_visible` is false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationships: Option<UserUpdateRequestDataRelationships>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserInvitationCreateRequestDataAttributes {
+    pub email: String,
+    #[serde(rename = "firstName")]
+    pub first_name: String,
+    #[serde(rename = "lastName")]
+    pub last_name: String,
+    pub roles: Vec<Role>,
+    #[serde(rename = "allAppsVisible")]
+    pub all_apps_visible: bool,
+    #[serde(rename = "provisioningAllowed")]
+    pub provisioning_allowed: bool,
+}
+
+enum_str!(AppsType{
+    Apps("apps"),
+});
+
+// the only variant, for `App::default()`; `enum_str!` doesn't take extra derives
+#[allow(clippy::derivable_impls)]
+impl Default for AppsType {
+    fn default() -> Self {
+        AppsType::Apps
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CertificateCreateRequest {
+    pub data: CertificateCreateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CertificateCreateRequestData {
+    #[serde(rename = "type")]
+    pub type_field: CertificatesType,
+    pub attributes: CertificateCreateRequestDataAttributes,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CertificateCreateRequestDataAttributes {
+    #[serde(rename = "certificateType")]
+    pub certificate_type: CertificateType,
+    #[serde(rename = "csrContent")]
+    pub csr_content: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleIdCreateRequest {
+    pub data: BundleIdCreateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleIdCreateRequestData {
+    #[serde(rename = "type")]
+    pub type_field: BundleIdsType,
+    pub attributes: BundleIdCreateRequestDataAttributes,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleIdCreateRequestDataAttributes {
+    pub name: String,
+    pub identifier: String,
+    pub platform: BundleIdPlatform,
+    #[serde(rename = "seedId")]
+    pub seed_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleIdUpdateRequest {
+    pub data: BundleIdUpdateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleIdUpdateRequestData {
+    #[serde(rename = "type")]
+    pub type_field: BundleIdsType,
+    pub id: String,
+    pub attributes: BundleIdUpdateRequestDataAttributes,
+}
+
+// Only the name can be changed, the identifier and platform are fixed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleIdUpdateRequestDataAttributes {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleIdCapabilitiesWithoutIncludesResponse {
+    pub data: Vec<BundleIdCapability>,
+    pub links: PagedDocumentLinks,
+    pub meta: PagingInformation,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleIdCapability {
+    #[serde(rename = "type")]
+    pub type_field: BundleIdCapabilitiesType,
+    pub id: String,
+    pub attributes: BundleIdCapabilityAttributes,
+    pub links: SelfLinks,
+}
+
+enum_str!(BundleIdCapabilitiesType{
+    BundleIdCapabilities("bundleIdCapabilities"),
+});
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleIdCapabilityAttributes {
+    #[serde(rename = "capabilityType")]
+    pub capability_type: CapabilityType,
+    #[serde(rename = "settings")]
+    pub settings: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleIdCapabilityCreateRequest {
+    pub data: BundleIdCapabilityCreateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleIdCapabilityCreateRequestData {
+    #[serde(rename = "type")]
+    pub type_field: BundleIdCapabilitiesType,
+    pub attributes: BundleIdCapabilityCreateRequestDataAttributes,
+    pub relationships: BundleIdCapabilityCreateRequestDataRelationships,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleIdCapabilityCreateRequestDataAttributes {
+    #[serde(rename = "capabilityType")]
+    pub capability_type: CapabilityType,
+    // e.g. the iCloud version, as the `settings` array Apple documents
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleIdCapabilityCreateRequestDataRelationships {
+    #[serde(rename = "bundleId")]
+    pub bundle_id: BundleIdCapabilityCreateRequestDataRelationshipsBundleId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleIdCapabilityCreateRequestDataRelationshipsBundleId {
+    pub data: BundleIdCapabilityCreateRequestDataRelationshipsBundleIdData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleIdCapabilityCreateRequestDataRelationshipsBundleIdData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: BundleIdsType,
+}
+
+enum_str!(CapabilityType{
+    Icloud("ICLOUD"),
+    InAppPurchase("IN_APP_PURCHASE"),
+    GameCenter("GAME_CENTER"),
+    PushNotifications("PUSH_NOTIFICATIONS"),
+    Wallet("WALLET"),
+    InterAppAudio("INTER_APP_AUDIO"),
+    Maps("MAPS"),
+    AssociatedDomains("ASSOCIATED_DOMAINS"),
+    PersonalVpn("PERSONAL_VPN"),
+    AppGroups("APP_GROUPS"),
+    Healthkit("HEALTHKIT"),
+    Homekit("HOMEKIT"),
+    WirelessAccessoryConfiguration("WIRELESS_ACCESSORY_CONFIGURATION"),
+    ApplePay("APPLE_PAY"),
+    DataProtection("DATA_PROTECTION"),
+    Sirikit("SIRIKIT"),
+    NetworkExtensions("NETWORK_EXTENSIONS"),
+    Multipath("MULTIPATH"),
+    HotSpot("HOT_SPOT"),
+    NfcTagReading("NFC_TAG_READING"),
+    Classkit("CLASSKIT"),
+    AutofillCredentialProvider("AUTOFILL_CREDENTIAL_PROVIDER"),
+    AccessWifiInformation("ACCESS_WIFI_INFORMATION"),
+    NetworkCustomProtocol("NETWORK_CUSTOM_PROTOCOL"),
+    CoremediaHlsLowLatency("COREMEDIA_HLS_LOW_LATENCY"),
+    SystemExtensionInstall("SYSTEM_EXTENSION_INSTALL"),
+    UserManagement("USER_MANAGEMENT"),
+    AppleIdAuth("APPLE_ID_AUTH"),
+    UserNotificationsCommunication("USERNOTIFICATIONS_COMMUNICATION"),
+    FamilyControls("FAMILY_CONTROLS"),
+});
+
+// Builds
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Build {
+    #[serde(rename = "type")]
+    pub type_field: BuildsType,
+    pub id: String,
+    pub attributes: BuildAttributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relationships: Option<BuildRelationships>,
+    pub links: SelfLinks,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuildRelationships {
+    #[serde(rename = "preReleaseVersion")]
+    pub pre_release_version: Option<BuildRelationshipsPreReleaseVersion>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuildRelationshipsPreReleaseVersion {
+    pub links: Option<SelfAndRelatedLinks>,
+    // with `include=preReleaseVersion`
+    pub data: Option<ResourceIdentifier>,
+}
+
+impl PageResponse<Build> {
+    // The pre-release version (train) of `build` found in `included`, needs `include=preReleaseVersion`
+    pub fn resolve_pre_release_version(&self, build: &Build) -> Option<&PreReleaseVersion> {
+        let identifier = build
+            .relationships
+            .as_ref()?
+            .pre_release_version
+            .as_ref()?
+            .data
+            .as_ref()?;
+        self.find_included(&identifier.id)
+    }
+}
+
+query_params!(BuildQuery: paged, include {
+    filter_app("filter[app]", String),
+    filter_pre_release_version("filter[preReleaseVersion]", String),
+    filter_pre_release_version_version("filter[preReleaseVersion.version]", String),
+    filter_processing_state("filter[processingState]", BuildProcessingState, filter_processing_states),
+    filter_version("filter[version]", String),
+    sort("sort",BuildSort,sort_many),
+});
+
+enum_str!(BuildSort {
+    PreReleaseVersion("preReleaseVersion"),
+    PreReleaseVersionDesc("-preReleaseVersion"),
+    UploadedDate("uploadedDate"),
+    UploadedDateDesc("-uploadedDate"),
+    Version("version"),
+    VersionDesc("-version"),
+});
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuildAttributes {
+    pub version: String,
+    #[serde(rename = "uploadedDate")]
+    pub uploaded_date: DateTime<Utc>,
+    #[serde(rename = "expirationDate")]
+    pub expiration_date: DateTime<Utc>,
+    pub expired: bool,
+    #[serde(rename = "minOsVersion")]
+    pub min_os_version: Option<String>,
+    #[serde(rename = "processingState")]
+    pub processing_state: BuildProcessingState,
+    #[serde(rename = "usesNonExemptEncryption")]
+    pub uses_non_exempt_encryption: Option<bool>,
+}
+
+enum_str!(BuildsType {
+    Builds("builds"),
+});
+
+enum_str!(BuildProcessingState {
+    Processing("PROCESSING"),
+    Failed("FAILED"),
+    Invalid("INVALID"),
+    Valid("VALID"),
+});
+
+// Build Beta Details, the TestFlight state of a build
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuildBetaDetail {
+    #[serde(rename = "type")]
+    pub type_field: BuildBetaDetailsType,
+    pub id: String,
+    pub attributes: BuildBetaDetailAttributes,
+    pub links: SelfLinks,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuildBetaDetailAttributes {
+    // testers are notified when the build is ready for them
+    #[serde(rename = "autoNotifyEnabled")]
+    pub auto_notify_enabled: bool,
+    #[serde(rename = "internalBuildState")]
+    pub internal_build_state: InternalBetaState,
+    #[serde(rename = "externalBuildState")]
+    pub external_build_state: ExternalBetaState,
+}
+
+enum_str!(BuildBetaDetailsType {
+    BuildBetaDetails("buildBetaDetails"),
+});
+
+enum_str!(InternalBetaState {
+    Processing("PROCESSING"),
+    ProcessingException("PROCESSING_EXCEPTION"),
+    MissingExportCompliance("MISSING_EXPORT_COMPLIANCE"),
+    ReadyForBetaTesting("READY_FOR_BETA_TESTING"),
+    InBetaTesting("IN_BETA_TESTING"),
+    Expired("EXPIRED"),
+    InExportComplianceReview("IN_EXPORT_COMPLIANCE_REVIEW"),
+});
+
+enum_str!(ExternalBetaState {
+    Processing("PROCESSING"),
+    ProcessingException("PROCESSING_EXCEPTION"),
+    MissingExportCompliance("MISSING_EXPORT_COMPLIANCE"),
+    ReadyForBetaTesting("READY_FOR_BETA_TESTING"),
+    InBetaTesting("IN_BETA_TESTING"),
+    Expired("EXPIRED"),
+    ReadyForBetaSubmission("READY_FOR_BETA_SUBMISSION"),
+    InExportComplianceReview("IN_EXPORT_COMPLIANCE_REVIEW"),
+    WaitingForBetaReview("WAITING_FOR_BETA_REVIEW"),
+    InBetaReview("IN_BETA_REVIEW"),
+    BetaRejected("BETA_REJECTED"),
+    BetaApproved("BETA_APPROVED"),
+});
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuildBetaDetailUpdateRequest {
+    pub data: BuildBetaDetailUpdateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuildBetaDetailUpdateRequestData {
+    #[serde(rename = "type")]
+    pub type_field: BuildBetaDetailsType,
+    pub id: String,
+    pub attributes: BuildBetaDetailUpdateRequestDataAttributes,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuildBetaDetailUpdateRequestDataAttributes {
+    #[serde(rename = "autoNotifyEnabled", skip_serializing_if = "Option::is_none")]
+    pub auto_notify_enabled: Option<bool>,
+}
+
+// Beta App Review Submissions, a build sent to beta review before external testers get it
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaAppReviewSubmission {
+    #[serde(rename = "type")]
+    pub type_field: BetaAppReviewSubmissionsType,
+    pub id: String,
+    pub attributes: BetaAppReviewSubmissionAttributes,
+    pub links: SelfLinks,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaAppReviewSubmissionAttributes {
+    #[serde(rename = "betaReviewState")]
+    pub beta_review_state: BetaReviewState,
+    #[serde(rename = "submittedDate")]
+    pub submitted_date: Option<DateTime<Utc>>,
+}
+
+enum_str!(BetaAppReviewSubmissionsType {
+    BetaAppReviewSubmissions("betaAppReviewSubmissions"),
+});
+
+enum_str!(BetaReviewState {
+    WaitingForReview("WAITING_FOR_REVIEW"),
+    InReview("IN_REVIEW"),
+    Rejected("REJECTED"),
+    Approved("APPROVED"),
+});
+
+// Apple requires `filter[build]`
+query_params!(BetaAppReviewSubmissionQuery: paged, include {
+    fields_beta_app_review_submissions("fields[betaAppReviewSubmissions]", String),
+    fields_builds("fields[builds]", String),
+    filter_build("filter[build]", String, filter_builds),
+    filter_beta_review_state("filter[betaReviewState]", BetaReviewState, filter_beta_review_states),
+});
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaAppReviewSubmissionCreateRequest {
+    pub data: BetaAppReviewSubmissionCreateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaAppReviewSubmissionCreateRequestData {
+    #[serde(rename = "type")]
+    pub type_field: BetaAppReviewSubmissionsType,
+    pub relationships: BetaAppReviewSubmissionCreateRequestDataRelationships,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaAppReviewSubmissionCreateRequestDataRelationships {
+    pub build: BetaAppReviewSubmissionCreateRequestDataRelationshipsBuild,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaAppReviewSubmissionCreateRequestDataRelationshipsBuild {
+    pub data: BetaAppReviewSubmissionCreateRequestDataRelationshipsBuildData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaAppReviewSubmissionCreateRequestDataRelationshipsBuildData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: BuildsType,
+}
+
+// App Encryption Declarations
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppEncryptionDeclaration {
+    #[serde(rename = "type")]
+    pub type_field: AppEncryptionDeclarationsType,
+    pub id: String,
+    pub attributes: AppEncryptionDeclarationAttributes,
+    pub links: SelfLinks,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppEncryptionDeclarationAttributes {
+    #[serde(rename = "appDescription")]
+    pub app_description: Option<String>,
+    #[serde(rename = "createdDate")]
+    pub created_date: Option<DateTime<Utc>>,
+    #[serde(rename = "usesEncryption")]
+    pub uses_encryption: Option<bool>,
+    pub exempt: Option<bool>,
+    #[serde(rename = "containsProprietaryCryptography")]
+    pub contains_proprietary_cryptography: Option<bool>,
+    #[serde(rename = "containsThirdPartyCryptography")]
+    pub contains_third_party_cryptography: Option<bool>,
+    #[serde(rename = "availableOnFrenchStore")]
+    pub available_on_french_store: Option<bool>,
+    pub platform: Option<Platform>,
+    #[serde(rename = "codeValue")]
+    pub code_value: Option<String>,
+    #[serde(rename = "appEncryptionDeclarationState")]
+    pub app_encryption_declaration_state: Option<AppEncryptionDeclarationState>,
+}
+
+enum_str!(AppEncryptionDeclarationsType {
+    AppEncryptionDeclarations("appEncryptionDeclarations"),
+});
+
+enum_str!(AppEncryptionDeclarationState {
+    Created("CREATED"),
+    InReview("IN_REVIEW"),
+    Approved("APPROVED"),
+    Rejected("REJECTED"),
+    Invalid("INVALID"),
+    Expired("EXPIRED"),
+});
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppEncryptionDeclarationCreateRequest {
+    pub data: AppEncryptionDeclarationCreateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppEncryptionDeclarationCreateRequestData {
+    #[serde(rename = "type")]
+    pub type_field: AppEncryptionDeclarationsType,
+    pub attributes: AppEncryptionDeclarationCreateRequestDataAttributes,
+    pub relationships: AppEncryptionDeclarationCreateRequestDataRelationships,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppEncryptionDeclarationCreateRequestDataAttributes {
+    #[serde(rename = "appDescription")]
+    pub app_description: String,
+    #[serde(rename = "containsProprietaryCryptography")]
+    pub contains_proprietary_cryptography: bool,
+    #[serde(rename = "containsThirdPartyCryptography")]
+    pub contains_third_party_cryptography: bool,
+    #[serde(rename = "availableOnFrenchStore")]
+    pub available_on_french_store: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppEncryptionDeclarationCreateRequestDataRelationships {
+    pub app: AppEncryptionDeclarationCreateRequestDataRelationshipsApp,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppEncryptionDeclarationCreateRequestDataRelationshipsApp {
+    pub data: AppEncryptionDeclarationCreateRequestDataRelationshipsAppData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppEncryptionDeclarationCreateRequestDataRelationshipsAppData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: AppsType,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppEncryptionDeclarationBuildsLinkagesRequest {
+    pub data: Vec<AppEncryptionDeclarationBuildsLinkagesRequestData>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppEncryptionDeclarationBuildsLinkagesRequestData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: BuildsType,
+}
+
+// App Store Version Submissions
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionSubmission {
+    #[serde(rename = "type")]
+    pub type_field: AppStoreVersionSubmissionsType,
+    pub id: String,
+    pub relationships: Option<AppStoreVersionSubmissionRelationships>,
+    pub links: SelfLinks,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionSubmissionRelationships {
+    #[serde(rename = "appStoreVersion")]
+    pub app_store_version: Option<AppStoreVersionSubmissionRelationshipsAppStoreVersion>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionSubmissionRelationshipsAppStoreVersion {
+    pub links: Option<SelfAndRelatedLinks>,
+    pub data: Option<ResourceIdentifier>,
+}
+
+enum_str!(AppStoreVersionSubmissionsType {
+    AppStoreVersionSubmissions("appStoreVersionSubmissions"),
+});
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionSubmissionCreateRequest {
+    pub data: AppStoreVersionSubmissionCreateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionSubmissionCreateRequestData {
+    #[serde(rename = "type")]
+    pub type_field: AppStoreVersionSubmissionsType,
+    pub relationships: AppStoreVersionSubmissionCreateRequestDataRelationships,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionSubmissionCreateRequestDataRelationships {
+    #[serde(rename = "appStoreVersion")]
+    pub app_store_version: AppStoreVersionSubmissionCreateRequestDataRelationshipsAppStoreVersion,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionSubmissionCreateRequestDataRelationshipsAppStoreVersion {
+    pub data: AppStoreVersionSubmissionCreateRequestDataRelationshipsAppStoreVersionData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppStoreVersionSubmissionCreateRequestDataRelationshipsAppStoreVersionData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: AppStoreVersionsType,
+}
+
+// Pre-Release Versions
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PreReleaseVersion {
+    #[serde(rename = "type")]
+    pub type_field: PreReleaseVersionsType,
+    pub id: String,
+    pub attributes: PreReleaseVersionAttributes,
+    pub links: SelfLinks,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PreReleaseVersionAttributes {
+    pub version: String,
+    pub platform: Platform,
+}
+
+enum_str!(PreReleaseVersionsType {
+    PreReleaseVersions("preReleaseVersions"),
+});
+
+// Territories
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Territory {
+    #[serde(rename = "type")]
+    pub type_field: TerritoriesType,
+    pub id: String,
+    pub attributes: TerritoryAttributes,
+    pub links: SelfLinks,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TerritoryAttributes {
+    pub currency: Option<String>,
+}
+
+enum_str!(TerritoriesType {
+    Territories("territories"),
+});
+
+query_params!(TerritoryQuery: paged {
+    fields_territories("fields[territories]", String),
+});
+
+// App Availability
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppAvailabilityCreateRequest {
+    pub data: AppAvailabilityCreateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppAvailabilityCreateRequestData {
+    #[serde(rename = "type")]
+    pub type_field: AppAvailabilitiesType,
+    pub attributes: AppAvailabilityCreateRequestDataAttributes,
+    pub relationships: AppAvailabilityCreateRequestDataRelationships,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppAvailabilityCreateRequestDataAttributes {
+    #[serde(rename = "availableInNewTerritories")]
+    pub available_in_new_territories: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppAvailabilityCreateRequestDataRelationships {
+    pub app: AppAvailabilityCreateRequestDataRelationshipsApp,
+    #[serde(rename = "availableTerritories")]
+    pub available_territories: AppAvailabilityCreateRequestDataRelationshipsAvailableTerritories,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppAvailabilityCreateRequestDataRelationshipsApp {
+    pub data: AppAvailabilityCreateRequestDataRelationshipsAppData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppAvailabilityCreateRequestDataRelationshipsAppData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: AppsType,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppAvailabilityCreateRequestDataRelationshipsAvailableTerritories {
+    pub data: Vec<AppAvailabilityCreateRequestDataRelationshipsAvailableTerritoriesData>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppAvailabilityCreateRequestDataRelationshipsAvailableTerritoriesData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: TerritoriesType,
+}
+
+enum_str!(AppAvailabilitiesType {
+    AppAvailabilities("appAvailabilities"),
+});
+
+// Beta Groups
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaGroup {
+    #[serde(rename = "type")]
+    pub type_field: BetaGroupsType,
+    pub id: String,
+    pub attributes: BetaGroupAttributes,
+    pub links: SelfLinks,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaGroupAttributes {
+    pub name: String,
+    #[serde(rename = "isInternalGroup")]
+    pub is_internal_group: Option<bool>,
+    #[serde(rename = "publicLinkEnabled")]
+    pub public_link_enabled: Option<bool>,
+    #[serde(rename = "publicLink")]
+    pub public_link: Option<String>,
+    #[serde(rename = "createdDate")]
+    pub created_date: Option<DateTime<Utc>>,
+}
+
+enum_str!(BetaGroupsType {
+    BetaGroups("betaGroups"),
+});
+
+query_params!(BetaGroupQuery: paged {
+    fields_beta_groups("fields[betaGroups]", String),
+    filter_app("filter[app]", String, filter_apps),
+    filter_id("filter[id]", String, filter_ids),
+    filter_is_internal_group("filter[isInternalGroup]", bool),
+    filter_name("filter[name]", String),
+    filter_public_link_enabled("filter[publicLinkEnabled]", bool),
+    sort("sort",BetaGroupSort,sort_many),
+});
+
+enum_str!(BetaGroupSort {
+    CreatedDate("createdDate"),
+    CreatedDateDesc("-createdDate"),
+    Name("name"),
+    NameDesc("-name"),
+    PublicLinkEnabled("publicLinkEnabled"),
+    PublicLinkEnabledDesc("-publicLinkEnabled"),
+});
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaGroupCreateRequest {
+    pub data: BetaGroupCreateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaGroupCreateRequestData {
+    #[serde(rename = "type")]
+    pub type_field: BetaGroupsType,
+    pub attributes: BetaGroupCreateRequestDataAttributes,
+    pub relationships: BetaGroupCreateRequestDataRelationships,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaGroupCreateRequestDataAttributes {
+    pub name: String,
+    #[serde(rename = "isInternalGroup", skip_serializing_if = "Option::is_none")]
+    pub is_internal_group: Option<bool>,
+    #[serde(rename = "publicLinkEnabled", skip_serializing_if = "Option::is_none")]
+    pub public_link_enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaGroupCreateRequestDataRelationships {
+    pub app: BetaGroupCreateRequestDataRelationshipsApp,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaGroupCreateRequestDataRelationshipsApp {
+    pub data: BetaGroupCreateRequestDataRelationshipsAppData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaGroupCreateRequestDataRelationshipsAppData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: AppsType,
+}
+
+// Beta Testers
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaTester {
+    #[serde(rename = "type")]
+    pub type_field: BetaTestersType,
+    pub id: String,
+    pub attributes: BetaTesterAttributes,
+    pub links: SelfLinks,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaTesterAttributes {
+    #[serde(rename = "firstName")]
+    pub first_name: Option<String>,
+    #[serde(rename = "lastName")]
+    pub last_name: Option<String>,
+    pub email: Option<String>,
+    #[serde(rename = "inviteType")]
+    pub invite_type: Option<BetaInviteType>,
+}
+
+enum_str!(BetaTestersType {
+    BetaTesters("betaTesters"),
+});
+
+enum_str!(BetaInviteType {
+    Email("EMAIL"),
+    PublicLink("PUBLIC_LINK"),
+});
+
+query_params!(BetaTesterQuery: paged {
+    fields_beta_testers("fields[betaTesters]", String),
+    filter_app("filter[apps]", String, filter_apps),
+    filter_beta_group("filter[betaGroups]", String, filter_beta_groups),
+    filter_build("filter[builds]", String, filter_builds),
+    filter_email("filter[email]", String),
+    filter_first_name("filter[firstName]", String),
+    filter_id("filter[id]", String, filter_ids),
+    filter_invite_type("filter[inviteType]", BetaInviteType),
+    filter_last_name("filter[lastName]", String),
+    sort("sort",BetaTesterSort,sort_many),
+});
+
+enum_str!(BetaTesterSort {
+    Email("email"),
+    EmailDesc("-email"),
+    FirstName("firstName"),
+    FirstNameDesc("-firstName"),
+    InviteType("inviteType"),
+    InviteTypeDesc("-inviteType"),
+    LastName("lastName"),
+    LastNameDesc("-lastName"),
+});
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaTesterCreateRequest {
+    pub data: BetaTesterCreateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaTesterCreateRequestData {
+    #[serde(rename = "type")]
+    pub type_field: BetaTestersType,
+    pub attributes: BetaTesterCreateRequestDataAttributes,
+    pub relationships: BetaTesterCreateRequestDataRelationships,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaTesterCreateRequestDataAttributes {
+    pub email: String,
+    #[serde(rename = "firstName", skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(rename = "lastName", skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+}
+
+// A new tester is invited to the groups or builds set here
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaTesterCreateRequestDataRelationships {
+    #[serde(rename = "betaGroups", skip_serializing_if = "Option::is_none")]
+    pub beta_groups: Option<BetaTesterCreateRequestDataRelationshipsBetaGroups>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub builds: Option<BetaTesterCreateRequestDataRelationshipsBuilds>,
+}
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct DeviceCreateRequestData {
+pub struct BetaTesterCreateRequestDataRelationshipsBetaGroups {
+    pub data: Vec<BetaTesterCreateRequestDataRelationshipsBetaGroupsData>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaTesterCreateRequestDataRelationshipsBetaGroupsData {
+    pub id: String,
     #[serde(rename = "type")]
-    pub type_field: DeviceType,
-    pub attributes: DeviceCreateRequestDataAttributes,
+    pub type_field: BetaGroupsType,
 }
 
-enum_str!(DeviceType {
-    Devices("devices"),
-});
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaTesterCreateRequestDataRelationshipsBuilds {
+    pub data: Vec<BetaTesterCreateRequestDataRelationshipsBuildsData>,
+}
 
-enum_str!(DeviceStatus {
-    Enabled("ENABLED"),
-    Disabled("DISABLED"),
-    Processing("PROCESSING"),
-    Ineligible("INELIGIBLE"),
-});
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaTesterCreateRequestDataRelationshipsBuildsData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: BuildsType,
+}
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct DeviceCreateRequestDataAttributes {
-    pub name: String,
-    pub platform: BundleIdPlatform,
-    pub udid: String,
+pub struct BetaGroupBetaTestersLinkagesRequest {
+    pub data: Vec<BetaGroupBetaTestersLinkagesRequestData>,
 }
 
-enum_str!(DeviceClass {
-    AppleWatch("APPLE_WATCH"),
-    Ipad("IPAD"),
-    Iphone("IPHONE"),
-    Ipod("IPOD"),
-    AppleTv("APPLE_TV"),
-    Mac("MAC"),
-});
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetaGroupBetaTestersLinkagesRequestData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: BetaTestersType,
+}
 
-enum_str!(BundleIdPlatform {
-    Ios("IOS"),
-    MacOS("MAC_OS"),
+// In-App Purchases (v2)
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InAppPurchaseV2 {
+    #[serde(rename = "type")]
+    pub type_field: InAppPurchasesType,
+    pub id: String,
+    pub attributes: InAppPurchaseV2Attributes,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InAppPurchaseV2Attributes {
+    pub name: String,
+    #[serde(rename = "productId")]
+    pub product_id: String,
+    #[serde(rename = "inAppPurchaseType")]
+    pub in_app_purchase_type: InAppPurchaseType,
+    pub state: InAppPurchaseState,
+    #[serde(rename = "reviewNote")]
+    pub review_note: Option<String>,
+    #[serde(rename = "familySharable", default)]
+    pub family_sharable: bool,
+}
+
+enum_str!(InAppPurchasesType {
+    InAppPurchases("inAppPurchases"),
 });
 
-//
+enum_str!(InAppPurchaseType {
+    Consumable("CONSUMABLE"),
+    NonConsumable("NON_CONSUMABLE"),
+    NonRenewingSubscription("NON_RENEWING_SUBSCRIPTION"),
+});
 
-query_params!(UsersQuery {
-    fields_apps("fields[apps]",String),
-    fields_users("fields[users]",String),
-    include("include",String),
-    limit("limit",i64),
-    sort("sort",UserSort),
-    filter_roles("filter[roles]",Role),
-    filter_visible_apps("filter[visibleApps]",String),
-    filter_username("filter[username]",String),
-    limit_visible_apps("limit[visibleApps]",i64),
+enum_str!(InAppPurchaseState {
+    MissingMetadata("MISSING_METADATA"),
+    WaitingForUpload("WAITING_FOR_UPLOAD"),
+    ProcessingContent("PROCESSING_CONTENT"),
+    ReadyToSubmit("READY_TO_SUBMIT"),
+    WaitingForReview("WAITING_FOR_REVIEW"),
+    InReview("IN_REVIEW"),
+    DeveloperActionNeeded("DEVELOPER_ACTION_NEEDED"),
+    PendingBinaryApproval("PENDING_BINARY_APPROVAL"),
+    Approved("APPROVED"),
+    DeveloperRemovedFromSale("DEVELOPER_REMOVED_FROM_SALE"),
+    RemovedFromSale("REMOVED_FROM_SALE"),
+    Rejected("REJECTED"),
 });
 
-query_params!(UserVisibleAppsQuery {
-    limit("limit",i64),
-    fields_apps("fields[apps]",String),
+query_params!(InAppPurchaseQuery: paged {
+    fields_in_app_purchases("fields[inAppPurchases]", String),
+    filter_in_app_purchase_type(
+        "filter[inAppPurchaseType]",
+        InAppPurchaseType,
+        filter_in_app_purchase_types
+    ),
+    filter_name("filter[name]", String, filter_names),
+    filter_product_id("filter[productId]", String, filter_product_ids),
+    filter_state("filter[state]", InAppPurchaseState, filter_states),
+    sort("sort", InAppPurchaseSort, sort_many),
 });
 
-enum_str!(UserSort{
-    LastName("lastName"),
-    LastNameDesc("-lastName"),
-    Username("username"),
-    UsernameDesc("-username"),
+enum_str!(InAppPurchaseSort {
+    InAppPurchaseType("inAppPurchaseType"),
+    InAppPurchaseTypeDesc("-inAppPurchaseType"),
+    Name("name"),
+    NameDesc("-name"),
 });
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct User {
+pub struct InAppPurchaseV2CreateRequest {
+    pub data: InAppPurchaseV2CreateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InAppPurchaseV2CreateRequestData {
     #[serde(rename = "type")]
-    pub type_field: UserType,
-    pub id: String,
-    pub attributes: UserAttributes,
+    pub type_field: InAppPurchasesType,
+    pub attributes: InAppPurchaseV2CreateRequestDataAttributes,
+    pub relationships: InAppPurchaseV2CreateRequestDataRelationships,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct UserAttributes {
-    pub username: String,
-    #[serde(rename = "firstName")]
-    pub first_name: String,
-    #[serde(rename = "lastName")]
-    pub last_name: String,
-    pub roles: Vec<Role>,
-    #[serde(rename = "allAppsVisible")]
-    pub all_apps_visible: bool,
-    #[serde(rename = "provisioningAllowed")]
-    pub provisioning_allowed: bool,
+pub struct InAppPurchaseV2CreateRequestDataAttributes {
+    pub name: String,
+    #[serde(rename = "productId")]
+    pub product_id: String,
+    #[serde(rename = "inAppPurchaseType")]
+    pub in_app_purchase_type: InAppPurchaseType,
+    #[serde(rename = "reviewNote", skip_serializing_if = "Option::is_none")]
+    pub review_note: Option<String>,
+    #[serde(rename = "familySharable", skip_serializing_if = "Option::is_none")]
+    pub family_sharable: Option<bool>,
 }
 
-enum_str!(UserType{
-    Users("users"),
-});
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InAppPurchaseV2CreateRequestDataRelationships {
+    pub app: InAppPurchaseV2CreateRequestDataRelationshipsApp,
+}
 
-enum_str!(Role{
-    Admin("ADMIN"),
-    Finance("FINANCE"),
-    AccountHolder("ACCOUNT_HOLDER"),
-    Sales("SALES"),
-    Marketing("MARKETING"),
-    AppManager("APP_MANAGER"),
-    Developer("DEVELOPER"),
-    AccessToReports("ACCESS_TO_REPORTS"),
-    CustomerSupport("CUSTOMER_SUPPORT"),
-    ImageManager("IMAGE_MANAGER"),
-    CreateApps("CREATE_APPS"),
-    CloudManagedDeveloperId("CLOUD_MANAGED_DEVELOPER_ID"),
-    CloudManagedAppDistribution("CLOUD_MANAGED_APP_DISTRIBUTION"),
-});
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InAppPurchaseV2CreateRequestDataRelationshipsApp {
+    pub data: InAppPurchaseV2CreateRequestDataRelationshipsAppData,
+}
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct UserUpdateRequest {
-    pub data: UserUpdateRequestData,
+pub struct InAppPurchaseV2CreateRequestDataRelationshipsAppData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: AppsType,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct UserUpdateRequestData {
+pub struct InAppPurchaseV2UpdateRequest {
+    pub data: InAppPurchaseV2UpdateRequestData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InAppPurchaseV2UpdateRequestData {
     #[serde(rename = "type")]
-    pub type_field: UserType,
+    pub type_field: InAppPurchasesType,
     pub id: String,
-    pub attributes: UserUpdateRequestDataAttributes,
-    pub relationships: UserUpdateRequestDataRelationships,
+    pub attributes: InAppPurchaseV2UpdateRequestDataAttributes,
 }
 
+// Only the attributes set are sent, the others are left unchanged
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InAppPurchaseV2UpdateRequestDataAttributes {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "reviewNote", skip_serializing_if = "Option::is_none")]
+    pub review_note: Option<String>,
+    #[serde(rename = "familySharable", skip_serializing_if = "Option::is_none")]
+    pub family_sharable: Option<bool>,
+}
+
+// Subscription Groups
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct UserUpdateRequestDataAttributes {
-    pub roles: Vec<Role>,
-    #[serde(rename = "allAppsVisible")]
-    pub all_apps_visible: bool,
-    #[serde(rename = "provisioningAllowed")]
-    pub provisioning_allowed: bool,
+pub struct SubscriptionGroup {
+    #[serde(rename = "type")]
+    pub type_field: SubscriptionGroupsType,
+    pub id: String,
+    pub attributes: SubscriptionGroupAttributes,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct UserUpdateRequestDataRelationships {
-    #[serde(rename = "visibleApps")]
-    pub visible_apps: UserUpdateRequestDataRelationshipsVisibleApps,
+pub struct SubscriptionGroupAttributes {
+    #[serde(rename = "referenceName")]
+    pub reference_name: String,
 }
 
+enum_str!(SubscriptionGroupsType {
+    SubscriptionGroups("subscriptionGroups"),
+});
+
+query_params!(SubscriptionGroupQuery: paged {
+    fields_subscription_groups("fields[subscriptionGroups]", String),
+    filter_reference_name("filter[referenceName]", String, filter_reference_names),
+    filter_subscriptions_state(
+        "filter[subscriptions.state]",
+        SubscriptionState,
+        filter_subscriptions_states
+    ),
+    sort("sort", SubscriptionGroupSort, sort_many),
+});
+
+enum_str!(SubscriptionGroupSort {
+    ReferenceName("referenceName"),
+    ReferenceNameDesc("-referenceName"),
+});
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct UserUpdateRequestDataRelationshipsVisibleApps {
-    pub data: Vec<UserUpdateRequestDataRelationshipsVisibleAppsData>,
+pub struct SubscriptionGroupCreateRequest {
+    pub data: SubscriptionGroupCreateRequestData,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct UserUpdateRequestDataRelationshipsVisibleAppsData {
-    pub id: String,
+pub struct SubscriptionGroupCreateRequestData {
     #[serde(rename = "type")]
-    pub type_field: AppsType,
+    pub type_field: SubscriptionGroupsType,
+    pub attributes: SubscriptionGroupAttributes,
+    pub relationships: SubscriptionGroupCreateRequestDataRelationships,
 }
 
-enum_str!(AppsType{
-    Apps("Apps"),
-});
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubscriptionGroupCreateRequestDataRelationships {
+    pub app: SubscriptionGroupCreateRequestDataRelationshipsApp,
+}
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct CertificateCreateRequest {
-    pub data: CertificateCreateRequestData,
+pub struct SubscriptionGroupCreateRequestDataRelationshipsApp {
+    pub data: SubscriptionGroupCreateRequestDataRelationshipsAppData,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct CertificateCreateRequestData {
+pub struct SubscriptionGroupCreateRequestDataRelationshipsAppData {
+    pub id: String,
     #[serde(rename = "type")]
-    pub type_field: CertificatesType,
-    pub attributes: CertificateCreateRequestDataAttributes,
+    pub type_field: AppsType,
 }
 
+// Subscriptions
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct CertificateCreateRequestDataAttributes {
-    #[serde(rename = "certificateType")]
-    pub certificate_type: CertificateType,
-    #[serde(rename = "csrContent")]
-    pub csr_content: String,
+pub struct Subscription {
+    #[serde(rename = "type")]
+    pub type_field: SubscriptionsType,
+    pub id: String,
+    pub attributes: SubscriptionAttributes,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct BundleIdCreateRequest {
-    pub data: BundleIdCreateRequestData,
+pub struct SubscriptionAttributes {
+    pub name: String,
+    #[serde(rename = "productId")]
+    pub product_id: String,
+    #[serde(rename = "subscriptionPeriod")]
+    pub subscription_period: Option<SubscriptionPeriod>,
+    pub state: SubscriptionState,
+    // the rank in its group, 1 being the highest level of service
+    #[serde(rename = "groupLevel")]
+    pub group_level: Option<i64>,
+    #[serde(rename = "reviewNote")]
+    pub review_note: Option<String>,
+    #[serde(rename = "familySharable", default)]
+    pub family_sharable: bool,
+}
+
+enum_str!(SubscriptionsType {
+    Subscriptions("subscriptions"),
+});
+
+enum_str!(SubscriptionPeriod {
+    OneWeek("ONE_WEEK"),
+    OneMonth("ONE_MONTH"),
+    TwoMonths("TWO_MONTHS"),
+    ThreeMonths("THREE_MONTHS"),
+    SixMonths("SIX_MONTHS"),
+    OneYear("ONE_YEAR"),
+});
+
+enum_str!(SubscriptionState {
+    MissingMetadata("MISSING_METADATA"),
+    ReadyToSubmit("READY_TO_SUBMIT"),
+    WaitingForReview("WAITING_FOR_REVIEW"),
+    InReview("IN_REVIEW"),
+    DeveloperActionNeeded("DEVELOPER_ACTION_NEEDED"),
+    PendingBinaryApproval("PENDING_BINARY_APPROVAL"),
+    Approved("APPROVED"),
+    DeveloperRemovedFromSale("DEVELOPER_REMOVED_FROM_SALE"),
+    RemovedFromSale("REMOVED_FROM_SALE"),
+    Rejected("REJECTED"),
+});
+
+query_params!(SubscriptionQuery: paged {
+    fields_subscriptions("fields[subscriptions]", String),
+    filter_name("filter[name]", String, filter_names),
+    filter_product_id("filter[productId]", String, filter_product_ids),
+    filter_state("filter[state]", SubscriptionState, filter_states),
+    sort("sort", SubscriptionSort, sort_many),
+});
+
+enum_str!(SubscriptionSort {
+    Name("name"),
+    NameDesc("-name"),
+});
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubscriptionCreateRequest {
+    pub data: SubscriptionCreateRequestData,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct BundleIdCreateRequestData {
+pub struct SubscriptionCreateRequestData {
     #[serde(rename = "type")]
-    pub type_field: BundleIdsType,
-    pub attributes: BundleIdCreateRequestDataAttributes,
+    pub type_field: SubscriptionsType,
+    pub attributes: SubscriptionCreateRequestDataAttributes,
+    pub relationships: SubscriptionCreateRequestDataRelationships,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct BundleIdCreateRequestDataAttributes {
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubscriptionCreateRequestDataAttributes {
     pub name: String,
-    pub identifier: String,
-    pub platform: BundleIdPlatform,
-    #[serde(rename = "seedId")]
-    pub seed_id: Option<String>,
+    #[serde(rename = "productId")]
+    pub product_id: String,
+    #[serde(rename = "subscriptionPeriod", skip_serializing_if = "Option::is_none")]
+    pub subscription_period: Option<SubscriptionPeriod>,
+    #[serde(rename = "groupLevel", skip_serializing_if = "Option::is_none")]
+    pub group_level: Option<i64>,
+    #[serde(rename = "reviewNote", skip_serializing_if = "Option::is_none")]
+    pub review_note: Option<String>,
+    #[serde(rename = "familySharable", skip_serializing_if = "Option::is_none")]
+    pub family_sharable: Option<bool>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct BundleIdCapabilitiesWithoutIncludesResponse {
-    pub data: Vec<BundleIdCapability>,
-    pub links: PagedDocumentLinks,
-    pub meta: PagingInformation,
+pub struct SubscriptionCreateRequestDataRelationships {
+    pub group: SubscriptionCreateRequestDataRelationshipsGroup,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct BundleIdCapability {
-    #[serde(rename = "type")]
-    pub type_field: BundleIdCapabilitiesType,
+pub struct SubscriptionCreateRequestDataRelationshipsGroup {
+    pub data: SubscriptionCreateRequestDataRelationshipsGroupData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubscriptionCreateRequestDataRelationshipsGroupData {
     pub id: String,
-    pub attributes: BundleIdCapabilityAttributes,
-    pub links: SelfLinks,
+    #[serde(rename = "type")]
+    pub type_field: SubscriptionGroupsType,
 }
 
-enum_str!(BundleIdCapabilitiesType{
-    BundleIdCapabilities("bundleIdCapabilities"),
+// Sales and Trends reports
+
+query_params!(SalesReportQuery {
+    filter_frequency("filter[frequency]", SalesReportFrequency),
+    filter_report_type("filter[reportType]", SalesReportType),
+    filter_report_sub_type("filter[reportSubType]", SalesReportSubType),
+    filter_vendor_number("filter[vendorNumber]", String),
+    // `YYYY-MM-DD`, `YYYY-MM` or `YYYY` depending on the frequency, the latest report when unset
+    filter_report_date("filter[reportDate]", String),
+    filter_version("filter[version]", String),
 });
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct BundleIdCapabilityAttributes {
-    #[serde(rename = "capabilityType")]
-    pub capability_type: CapabilityType,
-    #[serde(rename = "settings")]
-    pub settings: Option<serde_json::Value>,
-}
+enum_str!(SalesReportFrequency {
+    Daily("DAILY"),
+    Weekly("WEEKLY"),
+    Monthly("MONTHLY"),
+    Yearly("YEARLY"),
+});
 
-enum_str!(CapabilityType{
-    Icloud("ICLOUD"),
-    InAppPurchase("IN_APP_PURCHASE"),
-    GameCenter("GAME_CENTER"),
-    PushNotifications("PUSH_NOTIFICATIONS"),
-    Wallet("WALLET"),
-    InterAppAudio("INTER_APP_AUDIO"),
-    Maps("MAPS"),
-    AssociatedDomains("ASSOCIATED_DOMAINS"),
-    PersonalVpn("PERSONAL_VPN"),
-    AppGroups("APP_GROUPS"),
-    Healthkit("HEALTHKIT"),
-    Homekit("HOMEKIT"),
-    WirelessAccessoryConfiguration("WIRELESS_ACCESSORY_CONFIGURATION"),
-    ApplePay("APPLE_PAY"),
-    DataProtection("DATA_PROTECTION"),
-    Sirikit("SIRIKIT"),
-    NetworkExtensions("NETWORK_EXTENSIONS"),
-    Multipath("MULTIPATH"),
-    HotSpot("HOT_SPOT"),
-    NfcTagReading("NFC_TAG_READING"),
-    Classkit("CLASSKIT"),
-    AutofillCredentialProvider("AUTOFILL_CREDENTIAL_PROVIDER"),
-    AccessWifiInformation("ACCESS_WIFI_INFORMATION"),
-    NetworkCustomProtocol("NETWORK_CUSTOM_PROTOCOL"),
-    CoremediaHlsLowLatency("COREMEDIA_HLS_LOW_LATENCY"),
-    SystemExtensionInstall("SYSTEM_EXTENSION_INSTALL"),
-    UserManagement("USER_MANAGEMENT"),
-    AppleIdAuth("APPLE_ID_AUTH"),
-    UserNotificationsCommunication("USERNOTIFICATIONS_COMMUNICATION"),
-    FamilyControls("FAMILY_CONTROLS"),
+enum_str!(SalesReportType {
+    Sales("SALES"),
+    PreOrder("PRE_ORDER"),
+    Newsstand("NEWSSTAND"),
+    Subscription("SUBSCRIPTION"),
+    SubscriptionEvent("SUBSCRIPTION_EVENT"),
+    Subscriber("SUBSCRIBER"),
+    SubscriptionOfferCodeRedemption("SUBSCRIPTION_OFFER_CODE_REDEMPTION"),
+    Installs("INSTALLS"),
+    FirstAnnual("FIRST_ANNUAL"),
+    WinBackEligibility("WIN_BACK_ELIGIBILITY"),
+});
+
+enum_str!(SalesReportSubType {
+    Summary("SUMMARY"),
+    Detailed("DETAILED"),
+    SummaryInstallType("SUMMARY_INSTALL_TYPE"),
+    SummaryTerritory("SUMMARY_TERRITORY"),
+    SummaryChannel("SUMMARY_CHANNEL"),
+});
+
+// Finance reports
+
+query_params!(FinanceReportQuery {
+    // e.g. `US`, `EU` or `ZZ` for all regions, see Apple's region codes
+    filter_region_code("filter[regionCode]", String),
+    // fiscal month, `YYYY-MM`
+    filter_report_date("filter[reportDate]", String),
+    filter_report_type("filter[reportType]", FinanceReportType),
+    filter_vendor_number("filter[vendorNumber]", String),
 });
+
+enum_str!(FinanceReportType {
+    Financial("FINANCIAL"),
+    // only for the `Z1` region code
+    FinanceDetail("FINANCE_DETAIL"),
+});
+
+// `name` comes from Apple and may contain path separators, e.g. `Team/App Store`
+pub(crate) fn file_name(name: &str, extension: &str) -> String {
+    let name: String = name
+        .trim()
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+    let name = name.trim_start_matches('.');
+    if name.is_empty() {
+        format!("unnamed.{}", extension)
+    } else {
+        format!("{}.{}", name, extension)
+    }
+}
+
+fn write_file(
+    dir: &Path,
+    name: &str,
+    extension: &str,
+    content: &[u8],
+) -> crate::error::Result<PathBuf> {
+    let path = dir.join(file_name(name, extension));
+    match std::fs::write(&path, content) {
+        Ok(()) => Ok(path),
+        Err(err) => Err(crate::error::Error::message(format!(
+            "can't write {}: {}",
+            path.display(),
+            err
+        ))),
+    }
+}
@@ -8,6 +8,8 @@ pub enum Error {
     Convert(serde_json::Error),
     Reqwest(reqwest::Error),
     ServerErrors(ServerErrors),
+    // 401, `code` tells an invalid / expired token (`NOT_AUTHORIZED`) from other causes
+    Unauthorized { code: String, detail: String },
     Message(ErrorMessage),
     Other(Box<dyn std::error::Error + Sync + Send>),
 }
@@ -18,42 +20,96 @@ impl Error {
             content: content.into(),
         })
     }
+
+    /// The request timed out, see [reqwest::Error::is_timeout].
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Error::Reqwest(err) if err.is_timeout())
+    }
+
+    /// No connection could be made (DNS, refused, TLS), see [reqwest::Error::is_connect].
+    /// Errors from Apple itself come as [Error::ServerErrors] instead.
+    pub fn is_connect(&self) -> bool {
+        matches!(self, Error::Reqwest(err) if err.is_connect())
+    }
+
+    /// Reading the request or response body failed, see [reqwest::Error::is_body].
+    pub fn is_body(&self) -> bool {
+        matches!(self, Error::Reqwest(err) if err.is_body())
+    }
+
+    /// Apple refused a name already taken by another app.
+    ///
+    /// App Store Connect has no endpoint telling whether an app name is free, names of other
+    /// developers' apps can't be searched either. A taken name only shows up as this error when
+    /// the app is created or its localized name is saved.
+    pub fn is_name_in_use(&self) -> bool {
+        match self {
+            Error::ServerErrors(errors) => errors.iter().any(ServerError::is_name_in_use),
+            _ => false,
+        }
+    }
+
+    /// Apple answered `404 Not Found`, the resource doesn't exist or was deleted.
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            Error::ServerErrors(errors) => errors
+                .iter()
+                .any(|e| e.status_code() == Some(404) || e.code == "NOT_FOUND"),
+            _ => false,
+        }
+    }
+
+    /// Apple answered `429 Too Many Requests`, see [crate::client::ClientBuilder::set_max_retries].
+    pub fn is_rate_limited(&self) -> bool {
+        match self {
+            Error::ServerErrors(errors) => errors
+                .iter()
+                .any(|e| e.status_code() == Some(429) || e.code == "RATE_LIMIT_EXCEEDED"),
+            _ => false,
+        }
+    }
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut builder = f.debug_struct("apple_development::Error");
         match self {
-            Error::Key(err) => {
-                builder.field("kind", &"Key");
-                builder.field("source", err);
-            }
-            Error::Convert(err) => {
-                builder.field("kind", &"Convert");
-                builder.field("source", err);
-            }
-            Error::Reqwest(err) => {
-                builder.field("kind", &"Reqwest");
-                builder.field("source", err);
-            }
-            Error::ServerErrors(err) => {
-                builder.field("kind", &"ServerErrors");
-                builder.field("source", err);
-            }
-            Error::Message(err) => {
-                builder.field("kind", &"Message");
-                builder.field("source", err);
-            }
-            Error::Other(err) => {
-                builder.field("kind", &"Other");
-                builder.field("source", err);
+            Error::Key(err) => write!(f, "invalid key: {}", err),
+            Error::Convert(err) => write!(f, "unexpected json: {}", err),
+            Error::Reqwest(err) => write!(f, "request failed: {}", err),
+            Error::ServerErrors(err) => write!(f, "App Store Connect returned errors: {}", err),
+            Error::Unauthorized { code, detail } => {
+                write!(f, "unauthorized ({}): {}", code, detail)
             }
+            Error::Message(err) => write!(f, "{}", err),
+            Error::Other(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    // Errors of Apple and of this crate are their own cause
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Key(err) => Some(err),
+            Error::Convert(err) => Some(err),
+            Error::Reqwest(err) => Some(err),
+            Error::Other(err) => Some(err.as_ref()),
+            Error::ServerErrors(_) | Error::Unauthorized { .. } | Error::Message(_) => None,
         }
-        builder.finish()
     }
 }
 
-impl std::error::Error for Error {}
+// For programs returning `io::Result`, rate limited requests come as `WouldBlock`
+impl From<Error> for std::io::Error {
+    fn from(value: Error) -> Self {
+        let kind = if value.is_rate_limited() {
+            std::io::ErrorKind::WouldBlock
+        } else {
+            std::io::ErrorKind::Other
+        };
+        std::io::Error::new(kind, value)
+    }
+}
 
 pub type Result<A> = std::result::Result<A, Error>;
 
@@ -70,11 +126,74 @@ pub struct ServerError {
     pub detail: String,
 }
 
+impl ServerErrors {
+    pub fn iter(&self) -> std::slice::Iter<'_, ServerError> {
+        self.errors.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Whether any error has exactly this `code`, like `ENTITY_ERROR.ATTRIBUTE.INVALID`.
+    pub fn contains_code(&self, code: &str) -> bool {
+        self.errors.iter().any(|e| e.code == code)
+    }
+}
+
+impl ServerError {
+    /// `status` is the HTTP status as a string, `None` when it isn't a number.
+    pub fn status_code(&self) -> Option<u16> {
+        self.status.trim().parse().ok()
+    }
+
+    /// See [Error::is_name_in_use].
+    pub fn is_name_in_use(&self) -> bool {
+        let detail = self.detail.to_lowercase();
+        self.code.ends_with(".DUPLICATE")
+            || detail.contains("already being used")
+            || detail.contains("already in use")
+    }
+}
+
+impl IntoIterator for ServerErrors {
+    type Item = ServerError;
+    type IntoIter = std::vec::IntoIter<ServerError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ServerErrors {
+    type Item = &'a ServerError;
+    type IntoIter = std::slice::Iter<'a, ServerError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+// One `status code: detail` per error, separated by `; `
 impl Display for ServerErrors {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut builder = f.debug_struct("apple_development::ServerErrors");
-        builder.field("errors", &self.errors);
-        builder.finish()
+        for (index, error) in self.errors.iter().enumerate() {
+            if index > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for ServerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}: {}", self.status, self.code, self.detail)
     }
 }
 
@@ -92,6 +211,19 @@ impl From<jsonwebtoken::errors::Error> for Error {
     }
 }
 
+impl From<base64::DecodeError> for Error {
+    fn from(value: base64::DecodeError) -> Self {
+        Self::Other(Box::new(value))
+    }
+}
+
+#[cfg(feature = "csr")]
+impl From<openssl::error::ErrorStack> for Error {
+    fn from(value: openssl::error::ErrorStack) -> Self {
+        Self::Other(Box::new(value))
+    }
+}
+
 impl From<serde_json::Error> for Error {
     fn from(value: serde_json::Error) -> Self {
         Self::Convert(value)
@@ -105,9 +237,7 @@ pub struct ErrorMessage {
 
 impl Display for ErrorMessage {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut builder = f.debug_struct("apple_development::ErrorMessage");
-        builder.field("content", &self.content);
-        builder.finish()
+        f.write_str(&self.content)
     }
 }
 
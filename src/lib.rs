@@ -1,6 +1,10 @@
 pub mod entities;
 pub mod error;
 pub mod client;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(feature = "csr")]
+pub mod csr;
 #[cfg(test)]
 mod tests;
 
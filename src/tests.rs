@@ -1,28 +1,25 @@
-use base64::{DecodeError, Engine};
+use base64::Engine;
 
 use crate::client::{Client, ClientBuilder};
 use crate::entities::{
-    BundleIdCreateRequest, BundleIdCreateRequestData, BundleIdCreateRequestDataAttributes,
-    BundleIdPlatform, BundleIdQuery, BundleIdsType, CertificateCreateRequest,
-    CertificateCreateRequestData, CertificateCreateRequestDataAttributes, CertificateQuery,
-    CertificateType, CertificatesType, DeviceCreateRequest, DeviceCreateRequestData,
-    DeviceCreateRequestDataAttributes, DeviceQuery, DeviceType, ProfileCreateRequest,
-    ProfileCreateRequestAttributes, ProfileCreateRequestData,
-    ProfileCreateRequestDataRelationshipsBundleId,
+    App, AppQuery, AppSort, AppStoreState, AppsType, Build,
+    BundleIdCapabilitiesWithoutIncludesResponse, BundleIdCreateRequest, BundleIdCreateRequestData,
+    BundleIdCreateRequestDataAttributes, BundleIdPlatform, BundleIdQuery, BundleIdsType,
+    CapabilityType, CertificateCreateRequest, CertificateCreateRequestData,
+    CertificateCreateRequestDataAttributes, CertificateQuery, CertificateType, CertificatesType,
+    Device, DeviceCreateRequest, DeviceCreateRequestData, DeviceCreateRequestDataAttributes,
+    DeviceQuery, DeviceType, EntityResponse, Included, PageResponse, PagedDocumentLinks,
+    PagingInformation, Platform, ProfileCreateRequest, ProfileCreateRequestAttributes,
+    ProfileCreateRequestData, ProfileCreateRequestDataRelationshipsBundleId,
     ProfileCreateRequestDataRelationshipsBundleIdData,
     ProfileCreateRequestDataRelationshipsCertificates,
     ProfileCreateRequestDataRelationshipsCertificatesData,
     ProfileCreateRequestDataRelationshipsDevices, ProfileCreateRequestDataRelationshipsDevicesData,
-    ProfileCreateRequestRelationships, ProfileCreateRequestType, ProfileQuery, ProfileType,
+    ProfileCreateRequestRelationships, ProfileCreateRequestType, ProfileQuery, ProfileSort,
+    ProfileState, ProfileType, Role, UserUpdateRequestDataRelationshipsVisibleAppsData,
     UserVisibleAppsQuery, UsersQuery,
 };
-use crate::error::{Error, Result};
-
-impl From<DecodeError> for Error {
-    fn from(value: DecodeError) -> Self {
-        Self::Other(Box::new(value))
-    }
-}
+use crate::error::Result;
 
 fn gen_client() -> Result<Client> {
     ClientBuilder::default()
@@ -49,7 +46,7 @@ where
 
 #[tokio::test]
 async fn test_apps() -> Result<()> {
-    print(gen_client()?.apps(BundleIdQuery::default()).await);
+    print(gen_client()?.apps(AppQuery::default()).await);
     Ok(())
 }
 
@@ -153,11 +150,7 @@ async fn test_register_devices() -> Result<()> {
 
 #[tokio::test]
 async fn test_revokec_certificate() -> Result<()> {
-    print(
-        gen_client()?
-            .revoke_certificate("87792Q0000".to_string())
-            .await,
-    );
+    print(gen_client()?.revoke_certificate("87792Q0000").await);
     Ok(())
 }
 
@@ -234,3 +227,885 @@ async fn test_bundle_id_capabilities() -> Result<()> {
     print(gen_client()?.bundle_id_capabilities("XXXXXXXXXXX").await);
     Ok(())
 }
+
+#[test]
+fn test_certificate_type_all_sorted() {
+    let all = CertificateType::all();
+    assert_eq!(all.len(), 11);
+    assert!(all.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(all[0].display_name(), "Apple Development");
+}
+
+#[test]
+fn test_profile_type_all_sorted() {
+    let all = ProfileType::all();
+    assert_eq!(all.len(), 14);
+    assert!(all.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(all[0].display_name(), "iOS Ad Hoc");
+}
+
+#[test]
+fn test_certificate_to_pem() {
+    let attributes: crate::entities::CertificateAttributes =
+        serde_json::from_value(serde_json::json!({
+            "serialNumber": "1",
+            "certificateContent": base64::prelude::BASE64_STANDARD.encode([7u8; 100]),
+            "displayName": "name",
+            "name": "name",
+            "csrContent": null,
+            "platform": null,
+            "expirationDate": "2030-01-01T00:00:00.000+00:00",
+            "certificateType": "DEVELOPMENT",
+        }))
+        .unwrap();
+    assert_eq!(attributes.decoded_content().unwrap(), vec![7u8; 100]);
+    let pem = attributes.to_pem().unwrap();
+    let lines: Vec<&str> = pem.lines().collect();
+    assert_eq!(lines[0], "-----BEGIN CERTIFICATE-----");
+    assert_eq!(lines[1].len(), 64);
+    assert_eq!(lines[lines.len() - 1], "-----END CERTIFICATE-----");
+}
+
+#[test]
+fn test_filter_ids_queries() {
+    let queries = DeviceQuery::default()
+        .filter_ids(vec!["A".to_string(), "B".to_string()])
+        .queries();
+    assert_eq!(queries, vec![("filter[id]".to_string(), "A,B".to_string())]);
+    let queries = CertificateQuery::default()
+        .filter_id("A".to_string())
+        .queries();
+    assert_eq!(queries, vec![("filter[id]".to_string(), "A".to_string())]);
+    let queries = UsersQuery::default()
+        .filter_roles_any(vec![Role::Admin, Role::AccountHolder])
+        .queries();
+    assert_eq!(
+        queries,
+        vec![(
+            "filter[roles]".to_string(),
+            "ADMIN,ACCOUNT_HOLDER".to_string()
+        )]
+    );
+}
+
+#[test]
+fn test_is_locale() {
+    for locale in ["en-US", "ja", "zh-Hans", "zh-Hant-TW", "es-419"] {
+        assert!(crate::client::is_locale(locale), "{}", locale);
+    }
+    for locale in ["", "en_US", "EN-us", "english", "zh-hans", "en-US-x"] {
+        assert!(!crate::client::is_locale(locale), "{}", locale);
+    }
+}
+
+#[test]
+fn test_token_claims() {
+    use crate::client::{token_claims, DEFAULT_CLOCK_SKEW, MAX_TOKEN_LIFETIME};
+    use std::time::Duration;
+
+    let iss = "issuer".to_string();
+    let now = 1_700_000_000;
+    for (lifetime, clock_skew) in [
+        (Duration::from_secs(60 * 15), DEFAULT_CLOCK_SKEW),
+        (MAX_TOKEN_LIFETIME, DEFAULT_CLOCK_SKEW),
+        (MAX_TOKEN_LIFETIME, Duration::ZERO),
+        (Duration::from_secs(60), Duration::from_secs(60 * 10)),
+    ] {
+        let claims = token_claims(&iss, now, lifetime, clock_skew);
+        assert_eq!(claims.iat, now - clock_skew.as_secs() as usize);
+        assert!(claims.exp > now);
+        assert!(claims.exp - claims.iat <= MAX_TOKEN_LIFETIME.as_secs() as usize);
+        assert_eq!(claims.aud, "appstoreconnect-v1");
+    }
+    let claims = token_claims(&iss, now, MAX_TOKEN_LIFETIME, DEFAULT_CLOCK_SKEW);
+    assert_eq!(claims.exp, now + 60 * 15);
+}
+
+#[test]
+fn test_check_page_limit() {
+    use crate::client::check_page_limit;
+
+    let query = |limit: &str| vec![("limit".to_string(), limit.to_string())];
+    assert!(check_page_limit(&query("1")).is_ok());
+    assert!(check_page_limit(&query("200")).is_ok());
+    assert!(check_page_limit(&query("0")).is_err());
+    assert!(check_page_limit(&query("1000")).is_err());
+    // only the page size is checked, the limits of included relationships are smaller
+    assert!(check_page_limit(&[("limit[devices]".to_string(), "1000".to_string())]).is_ok());
+}
+
+#[test]
+fn test_parse_rate_limit() {
+    use crate::client::{parse_rate_limit, RateLimitInfo};
+
+    assert_eq!(
+        parse_rate_limit("user-hour-lim:3600;user-hour-rem:3599;"),
+        Some(RateLimitInfo {
+            user_hour_limit: 3600,
+            user_hour_remaining: 3599,
+        })
+    );
+    assert_eq!(parse_rate_limit("user-hour-lim:3600;"), None);
+    assert_eq!(parse_rate_limit("garbage"), None);
+}
+
+#[test]
+fn test_parse_retry_after() {
+    use crate::client::parse_retry_after;
+    use std::time::Duration;
+    assert_eq!(parse_retry_after("30"), Some(Duration::from_secs(30)));
+    assert_eq!(parse_retry_after(" 0 "), Some(Duration::ZERO));
+    assert_eq!(
+        parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+        Some(Duration::ZERO)
+    );
+    let later = (chrono::Utc::now() + chrono::Duration::seconds(120)).to_rfc2822();
+    let delay = parse_retry_after(&later).unwrap();
+    assert!(delay > Duration::from_secs(100) && delay <= Duration::from_secs(120));
+    assert_eq!(parse_retry_after("soon"), None);
+}
+
+#[test]
+fn test_retry_delay() {
+    use std::time::Duration;
+    assert_eq!(Client::retry_delay(1, None), Duration::from_secs(1));
+    assert_eq!(Client::retry_delay(3, None), Duration::from_secs(4));
+    assert_eq!(Client::retry_delay(10, None), Duration::from_secs(60));
+    assert_eq!(Client::retry_delay(1, Some("30")), Duration::from_secs(30));
+    assert_eq!(
+        Client::retry_delay(1, Some("86400")),
+        Duration::from_secs(60)
+    );
+    let later = (chrono::Utc::now() + chrono::Duration::hours(5)).to_rfc2822();
+    assert_eq!(
+        Client::retry_delay(1, Some(&later)),
+        Duration::from_secs(60)
+    );
+}
+
+#[test]
+fn test_is_bundle_identifier() {
+    for identifier in ["com.example.app", "com.example.*", "io.my-team.App2"] {
+        assert!(
+            crate::client::is_bundle_identifier(identifier),
+            "{}",
+            identifier
+        );
+    }
+    for identifier in [
+        "",
+        "app",
+        "com..app",
+        "com.example.",
+        "*",
+        "com.*.app",
+        "com.exa mple",
+    ] {
+        assert!(
+            !crate::client::is_bundle_identifier(identifier),
+            "{}",
+            identifier
+        );
+    }
+}
+
+fn app_json(id: &str) -> serde_json::Value {
+    let mut relationships = serde_json::Map::new();
+    for key in [
+        "ciProduct",
+        "betaTesters",
+        "betaGroups",
+        "appStoreVersions",
+        "preReleaseVersions",
+        "betaAppLocalizations",
+        "builds",
+        "betaLicenseAgreement",
+        "betaAppReviewDetail",
+        "appInfos",
+        "appClips",
+        "appPricePoints",
+        "pricePoints",
+        "endUserLicenseAgreement",
+        "preOrder",
+        "prices",
+        "appPriceSchedule",
+        "availableTerritories",
+        "appAvailability",
+        "inAppPurchases",
+        "gameCenterEnabledVersions",
+        "perfPowerMetrics",
+        "appCustomProductPages",
+        "appEvents",
+        "reviewSubmissions",
+        "customerReviews",
+    ] {
+        relationships.insert(
+            key.to_string(),
+            serde_json::json!({ "links": { "self": "self", "related": "related" } }),
+        );
+    }
+    serde_json::json!({
+        "type": "apps",
+        "id": id,
+        "attributes": {
+            "name": "Example",
+            "bundleId": "com.example.app",
+            "sku": "EXAMPLE",
+            "primaryLocale": "en-US",
+            "isOrEverWasMadeForKids": false,
+            "availableInNewTerritories": true,
+        },
+        "relationships": relationships,
+        "links": { "self": "self" },
+    })
+}
+
+fn app_store_version_json(id: &str, version: &str) -> serde_json::Value {
+    serde_json::json!({
+        "type": "appStoreVersions",
+        "id": id,
+        "attributes": {
+            "platform": "IOS",
+            "versionString": version,
+            "appStoreState": "READY_FOR_SALE",
+            "copyright": null,
+            "releaseType": "MANUAL",
+            "earliestReleaseDate": null,
+            "downloadable": true,
+            "createdDate": "2023-01-01T00:00:00.000-08:00",
+        },
+        "links": { "self": "self" },
+    })
+}
+
+#[test]
+fn test_resource_types() {
+    use crate::entities::AppsType;
+
+    let app: App = serde_json::from_value(app_json("A1")).unwrap();
+    assert_eq!(app.type_field, AppsType::Apps);
+    let device: Device = serde_json::from_value(serde_json::json!({
+        "type": "devices",
+        "id": "D1",
+        "attributes": {
+            "deviceClass": "IPHONE",
+            "model": "iPhone 15",
+            "name": "Test iPhone",
+            "platform": "IOS",
+            "status": "ENABLED",
+            "udid": "00008030-000000000000002E",
+            "addedDate": "2024-01-01T00:00:00.000+00:00",
+        },
+        "links": { "self": "self" },
+    }))
+    .unwrap();
+    assert_eq!(device.type_field, DeviceType::Devices);
+    let mut app = app_json("A1");
+    app["type"] = serde_json::json!("devices");
+    assert!(serde_json::from_value::<App>(app).is_err());
+}
+
+#[test]
+fn test_resolve_versions() {
+    let mut app = app_json("A1");
+    app["relationships"]["appStoreVersions"]["data"] = serde_json::json!([
+        { "type": "appStoreVersions", "id": "V2" },
+        { "type": "appStoreVersions", "id": "V1" },
+    ]);
+    let page: PageResponse<App> = serde_json::from_value(serde_json::json!({
+        "data": [app, app_json("A2")],
+        "included": [
+            app_store_version_json("V1", "1.0"),
+            { "type": "appInfos", "id": "I1" },
+            app_store_version_json("V2", "1.1"),
+        ],
+        "links": { "self": "self" },
+        "meta": { "paging": { "total": 2, "limit": 50 } },
+    }))
+    .unwrap();
+    let versions: Vec<&str> = page
+        .resolve_versions(&page.data[0])
+        .iter()
+        .map(|v| v.attributes.version_string.as_str())
+        .collect();
+    assert_eq!(versions, vec!["1.1", "1.0"]);
+    assert!(page.resolve_versions(&page.data[1]).is_empty());
+    assert!(matches!(page.included[1], Included::Other(_)));
+}
+
+#[test]
+fn test_resolve_app_includes() {
+    let mut app = app_json("A1");
+    app["relationships"]["appStoreVersions"]["data"] =
+        serde_json::json!([{ "type": "appStoreVersions", "id": "V1" }]);
+    app["relationships"]["builds"]["data"] = serde_json::json!([{ "type": "builds", "id": "B1" }]);
+    let response: EntityResponse<App> = serde_json::from_value(serde_json::json!({
+        "data": app,
+        "included": [
+            app_store_version_json("V1", "1.0"),
+            {
+                "type": "builds",
+                "id": "B1",
+                "attributes": {
+                    "version": "42",
+                    "uploadedDate": "2023-01-01T00:00:00.000-08:00",
+                    "expirationDate": "2023-04-01T00:00:00.000-08:00",
+                    "expired": false,
+                    "processingState": "VALID",
+                },
+                "links": { "self": "self" },
+            },
+        ],
+        "links": { "self": "self" },
+    }))
+    .unwrap();
+    assert_eq!(response.resolve_versions()[0].id, "V1");
+    assert_eq!(response.resolve_builds()[0].attributes.version, "42");
+    let build: &Build = response.find_included("B1").unwrap();
+    assert_eq!(build.attributes.version, "42");
+    assert!(response.find_included::<Build>("V1").is_none());
+    assert!(response.find_included::<Device>("B1").is_none());
+}
+
+#[test]
+fn test_credential_problems() {
+    assert!(crate::client::credential_problems(
+        "2X9R4HXF34",
+        "57246542-96fe-1a63-e053-0824d011072a"
+    )
+    .is_empty());
+    let problems =
+        crate::client::credential_problems("2X9R4HXF", "57246542-96fe-1a63-e053-0824d011072a");
+    assert_eq!(
+        problems,
+        vec!["kid should be 10 characters, got 8".to_string()]
+    );
+    let problems = crate::client::credential_problems("2X9R4HXF34", "57246542-96fe-1a63-e053");
+    assert_eq!(problems.len(), 1);
+    assert!(problems[0].starts_with("iss should be a UUID"));
+
+    let builder = crate::client::ClientBuilder::default()
+        .with_kid("2X9R4HXF")
+        .with_iss("57246542-96fe-1a63-e053-0824d011072a")
+        .with_lenient_credentials(true);
+    assert_eq!(
+        builder.credential_problems(),
+        vec!["kid should be 10 characters, got 8".to_string()]
+    );
+}
+
+fn profile_create_request(profile_type: ProfileType, devices: Vec<&str>) -> ProfileCreateRequest {
+    ProfileCreateRequest {
+        data: ProfileCreateRequestData {
+            attributes: ProfileCreateRequestAttributes {
+                name: "profileName".to_string(),
+                profile_type,
+            },
+            relationships: ProfileCreateRequestRelationships {
+                bundle_id: ProfileCreateRequestDataRelationshipsBundleId {
+                    data: ProfileCreateRequestDataRelationshipsBundleIdData {
+                        id: "FJXB650000".to_string(),
+                        type_field: BundleIdsType::BundleIds,
+                    },
+                },
+                certificates: ProfileCreateRequestDataRelationshipsCertificates {
+                    data: vec![ProfileCreateRequestDataRelationshipsCertificatesData {
+                        id: "87792Q0000".to_string(),
+                        type_field: CertificatesType::Certificates,
+                    }],
+                },
+                devices: Some(ProfileCreateRequestDataRelationshipsDevices {
+                    data: devices
+                        .into_iter()
+                        .map(|id| ProfileCreateRequestDataRelationshipsDevicesData {
+                            id: id.to_string(),
+                            type_field: DeviceType::Devices,
+                        })
+                        .collect(),
+                }),
+            },
+            type_field: ProfileCreateRequestType::Profiles,
+        },
+    }
+}
+
+#[test]
+fn test_profile_create_request_validate() {
+    assert!(
+        profile_create_request(ProfileType::IosAppAdhoc, vec!["25D9760000"])
+            .validate()
+            .is_ok()
+    );
+    assert!(profile_create_request(ProfileType::IosAppAdhoc, vec![])
+        .validate()
+        .is_err());
+    assert!(profile_create_request(ProfileType::IosAppStore, vec![])
+        .validate()
+        .is_ok());
+    assert!(
+        profile_create_request(ProfileType::IosAppStore, vec!["25D9760000"])
+            .validate()
+            .is_err()
+    );
+}
+
+#[test]
+fn test_display() {
+    let app: App = serde_json::from_value(app_json("A1")).unwrap();
+    assert_eq!(app.to_string(), "Example (com.example.app)");
+    let device: Device = serde_json::from_value(serde_json::json!({
+        "type": "devices",
+        "id": "D1",
+        "attributes": {
+            "addedDate": "2022-12-10T12:02:45.000+00:00",
+            "name": "mini",
+            "deviceClass": "IPAD",
+            "model": "iPad mini",
+            "udid": "00008020-000000000000002E",
+            "platform": "IOS",
+            "status": "ENABLED",
+        },
+        "links": { "self": "self" },
+    }))
+    .unwrap();
+    assert_eq!(device.to_string(), "mini (00008020-000000000000002E)");
+}
+
+#[cfg(feature = "csr")]
+#[test]
+fn test_generate_csr() {
+    let (pem, key) = crate::csr::generate_csr("appstoreconnect", "ci@example.com").unwrap();
+    let request = openssl::x509::X509Req::from_pem(pem.as_bytes()).unwrap();
+    let entry = |nid| {
+        request
+            .subject_name()
+            .entries_by_nid(nid)
+            .next()
+            .map(|entry| entry.data().as_slice().to_vec())
+    };
+    assert_eq!(
+        entry(openssl::nid::Nid::COMMONNAME).unwrap(),
+        b"appstoreconnect"
+    );
+    assert_eq!(
+        entry(openssl::nid::Nid::PKCS9_EMAILADDRESS).unwrap(),
+        b"ci@example.com"
+    );
+    let key = openssl::pkey::PKey::private_key_from_pkcs8(&key).unwrap();
+    assert!(request.verify(&key).unwrap());
+}
+
+#[test]
+fn test_server_errors_iter() {
+    let errors: crate::error::ServerErrors = serde_json::from_value(serde_json::json!({
+        "errors": [
+            { "status": "409", "code": "ENTITY_ERROR.ATTRIBUTE.INVALID", "title": "t", "detail": "name" },
+            { "status": "409", "code": "ENTITY_ERROR.ATTRIBUTE.INVALID", "title": "t", "detail": "udid" },
+        ],
+    }))
+    .unwrap();
+    assert_eq!(errors.len(), 2);
+    assert!(!errors.is_empty());
+    let details: Vec<&str> = errors.iter().map(|e| e.detail.as_str()).collect();
+    assert_eq!(details, vec!["name", "udid"]);
+    assert_eq!((&errors).into_iter().count(), 2);
+    let owned: Vec<_> = errors.into_iter().collect();
+    assert_eq!(owned[1].detail, "udid");
+}
+
+#[test]
+fn test_bundle_id_platform_spellings() {
+    for spelling in ["\"MAC_OS\"", "\"MACOS\""] {
+        let platform: BundleIdPlatform = serde_json::from_str(spelling).unwrap();
+        assert_eq!(platform, BundleIdPlatform::MacOS);
+        assert_eq!(serde_json::to_string(&platform).unwrap(), "\"MAC_OS\"");
+    }
+    assert!(serde_json::from_str::<BundleIdPlatform>("\"MAC\"").is_err());
+}
+
+#[test]
+fn test_universal_bundle_id() {
+    let attributes: crate::entities::BundleIdAttributes =
+        serde_json::from_value(serde_json::json!({
+            "name": "Example",
+            "identifier": "com.example.app",
+            "platform": "UNIVERSAL",
+            "seedId": "TEAM123456",
+        }))
+        .unwrap();
+    assert_eq!(attributes.platform, BundleIdPlatform::Universal);
+    assert_eq!(
+        serde_json::to_value(&attributes).unwrap()["platform"],
+        "UNIVERSAL"
+    );
+}
+
+#[test]
+fn test_platform_round_trip() {
+    for (platform, spelling) in [
+        (Platform::Ios, "\"IOS\""),
+        (Platform::MacOs, "\"MAC_OS\""),
+        (Platform::TvOs, "\"TV_OS\""),
+        (Platform::VisionOs, "\"VISION_OS\""),
+    ] {
+        assert_eq!(serde_json::to_string(&platform).unwrap(), spelling);
+        assert_eq!(
+            serde_json::from_str::<Platform>(spelling).unwrap(),
+            platform
+        );
+    }
+    assert!(serde_json::from_str::<BundleIdPlatform>("\"TV_OS\"").is_err());
+}
+
+#[test]
+fn test_paging_information_keeps_extra_meta() {
+    let meta: PagingInformation = serde_json::from_value(serde_json::json!({
+        "paging": { "total": 3, "limit": 2 },
+        "rateLimit": "user-hour-lim:3500;user-hour-rem:3499;",
+    }))
+    .unwrap();
+    assert_eq!(meta.paging.total, 3);
+    assert_eq!(
+        meta.extra["rateLimit"],
+        "user-hour-lim:3500;user-hour-rem:3499;"
+    );
+    let json = serde_json::to_value(&meta).unwrap();
+    assert_eq!(json["rateLimit"], meta.extra["rateLimit"]);
+}
+
+#[test]
+fn test_is_name_in_use() {
+    let error = |code: &str, detail: &str| {
+        crate::error::Error::ServerErrors(crate::error::ServerErrors {
+            errors: vec![crate::error::ServerError {
+                status: "409".to_string(),
+                code: code.to_string(),
+                title: "An attribute value is invalid.".to_string(),
+                detail: detail.to_string(),
+            }],
+        })
+    };
+    assert!(error(
+        "ENTITY_ERROR.ATTRIBUTE.INVALID",
+        "The App Name you entered is already being used."
+    )
+    .is_name_in_use());
+    assert!(error("ENTITY_ERROR.ATTRIBUTE.INVALID.DUPLICATE", "name").is_name_in_use());
+    assert!(!error("ENTITY_ERROR.ATTRIBUTE.INVALID", "name is too long").is_name_in_use());
+    assert!(!crate::error::Error::message("already in use").is_name_in_use());
+}
+
+#[test]
+fn test_error_codes() {
+    let error = |status: &str, code: &str| crate::error::ServerError {
+        status: status.to_string(),
+        code: code.to_string(),
+        title: "title".to_string(),
+        detail: "detail".to_string(),
+    };
+    assert_eq!(error("404", "NOT_FOUND").status_code(), Some(404));
+    assert_eq!(error("", "NOT_FOUND").status_code(), None);
+
+    let errors = crate::error::ServerErrors {
+        errors: vec![
+            error("409", "ENTITY_ERROR.ATTRIBUTE.INVALID"),
+            error("409", "ENTITY_ERROR.RELATIONSHIP.INVALID"),
+        ],
+    };
+    assert!(errors.contains_code("ENTITY_ERROR.RELATIONSHIP.INVALID"));
+    assert!(!errors.contains_code("ENTITY_ERROR"));
+
+    let not_found = crate::error::Error::ServerErrors(crate::error::ServerErrors {
+        errors: vec![error("404", "NOT_FOUND")],
+    });
+    assert!(not_found.is_not_found());
+    assert!(!not_found.is_rate_limited());
+    let rate_limited = crate::error::Error::ServerErrors(crate::error::ServerErrors {
+        errors: vec![error("429", "RATE_LIMIT_EXCEEDED")],
+    });
+    assert!(rate_limited.is_rate_limited());
+    assert!(!rate_limited.is_not_found());
+    assert!(!crate::error::Error::message("404").is_not_found());
+}
+
+#[test]
+fn test_into_io_error() {
+    let rate_limited = crate::error::Error::ServerErrors(crate::error::ServerErrors {
+        errors: vec![crate::error::ServerError {
+            status: "429".to_string(),
+            code: "RATE_LIMIT_EXCEEDED".to_string(),
+            title: "The request rate limit has been reached.".to_string(),
+            detail: "detail".to_string(),
+        }],
+    });
+    let io_error = std::io::Error::from(rate_limited);
+    assert_eq!(io_error.kind(), std::io::ErrorKind::WouldBlock);
+
+    let message = crate::error::Error::message("no such app");
+    let text = message.to_string();
+    let io_error = std::io::Error::from(message);
+    assert_eq!(io_error.kind(), std::io::ErrorKind::Other);
+    assert_eq!(io_error.to_string(), text);
+}
+
+#[test]
+fn test_error_source() {
+    use std::error::Error;
+
+    let json_error = serde_json::from_str::<App>("{").unwrap_err();
+    let json_text = json_error.to_string();
+    let error = crate::error::Error::from(json_error);
+    assert_eq!(error.source().unwrap().to_string(), json_text);
+
+    let error = crate::error::Error::from(base64::DecodeError::InvalidLength);
+    assert!(error.source().unwrap().is::<base64::DecodeError>());
+
+    assert!(crate::error::Error::message("message").source().is_none());
+}
+
+#[test]
+fn test_error_display() {
+    let error = |status: &str, code: &str, detail: &str| crate::error::ServerError {
+        status: status.to_string(),
+        code: code.to_string(),
+        title: "title".to_string(),
+        detail: detail.to_string(),
+    };
+    let errors = crate::error::Error::ServerErrors(crate::error::ServerErrors {
+        errors: vec![
+            error("409", "ENTITY_ERROR.ATTRIBUTE.INVALID", "name is too long"),
+            error("409", "ENTITY_ERROR.ATTRIBUTE.REQUIRED", "sku is required"),
+        ],
+    });
+    assert_eq!(
+        errors.to_string(),
+        "App Store Connect returned errors: \
+         409 ENTITY_ERROR.ATTRIBUTE.INVALID: name is too long; \
+         409 ENTITY_ERROR.ATTRIBUTE.REQUIRED: sku is required"
+    );
+    assert_eq!(
+        crate::error::Error::message("no such app").to_string(),
+        "no such app"
+    );
+    let unauthorized = crate::error::Error::Unauthorized {
+        code: "NOT_AUTHORIZED".to_string(),
+        detail: "the token expired".to_string(),
+    };
+    assert_eq!(
+        unauthorized.to_string(),
+        "unauthorized (NOT_AUTHORIZED): the token expired"
+    );
+}
+
+#[test]
+fn test_bundle_id_capabilities_response() {
+    let response: BundleIdCapabilitiesWithoutIncludesResponse =
+        serde_json::from_value(serde_json::json!({
+            "data": [{
+                "type": "bundleIdCapabilities",
+                "id": "BID1_ASSOCIATED_DOMAINS",
+                "attributes": {
+                    "capabilityType": "ASSOCIATED_DOMAINS",
+                    "settings": null,
+                },
+                "links": { "self": "self" },
+            }],
+            "links": { "self": "self" },
+            "meta": { "paging": { "total": 1, "limit": 20 } },
+        }))
+        .unwrap();
+    assert_eq!(
+        response.data[0].attributes.capability_type,
+        CapabilityType::AssociatedDomains
+    );
+    assert_eq!(response.meta.paging.total, 1);
+}
+
+#[test]
+fn test_visible_apps_type() {
+    let data = UserUpdateRequestDataRelationshipsVisibleAppsData {
+        id: "A1".to_string(),
+        type_field: AppsType::Apps,
+    };
+    assert_eq!(
+        serde_json::to_value(&data).unwrap(),
+        serde_json::json!({ "id": "A1", "type": "apps" })
+    );
+}
+
+#[test]
+fn test_sort_many() {
+    use crate::entities::CertificateSort;
+
+    let queries = CertificateQuery::default()
+        .sort_many(vec![
+            CertificateSort::CertificateType,
+            CertificateSort::DisplayNameDesc,
+        ])
+        .queries();
+    assert_eq!(
+        queries,
+        vec![(
+            "sort".to_string(),
+            "certificateType,-displayName".to_string()
+        )]
+    );
+    let queries = CertificateQuery::default()
+        .sort(CertificateSort::SerialNumber)
+        .queries();
+    assert_eq!(
+        queries,
+        vec![("sort".to_string(), "serialNumber".to_string())]
+    );
+}
+
+#[test]
+fn test_profile_state_query_key() {
+    use crate::entities::ProfileState;
+
+    let queries = ProfileQuery::default()
+        .filter_profile_state(ProfileState::ACTIVE)
+        .queries();
+    assert_eq!(
+        queries,
+        vec![("filter[profileState]".to_string(), "ACTIVE".to_string())]
+    );
+}
+
+#[test]
+fn test_profile_query_keys_have_no_whitespace() {
+    let queries = ProfileQuery::default()
+        .include("bundleId".to_string())
+        .limit(10)
+        .fields_certificates("name".to_string())
+        .fields_devices("name".to_string())
+        .filter_profiles("profiles".to_string())
+        .filter_id("id".to_string())
+        .filter_name("name".to_string())
+        .limit_certificates(10)
+        .limit_devices(10)
+        .sort(ProfileSort::Name)
+        .fields_bundle_ids("name".to_string())
+        .filter_profile_state(ProfileState::ACTIVE)
+        .filter_profile_type(ProfileType::IosAppStore)
+        .queries();
+    assert_eq!(queries.len(), 13);
+    for (key, _) in queries {
+        assert_eq!(key.trim(), key);
+    }
+}
+
+#[test]
+fn test_query_presets() {
+    let queries = ProfileQuery::default()
+        .for_platform(BundleIdPlatform::MacOS)
+        .queries();
+    assert_eq!(
+        queries,
+        vec![(
+            "filter[profileType]".to_string(),
+            "MAC_APP_DEVELOPMENT,MAC_APP_STORE,MAC_APP_DIRECT".to_string()
+        )]
+    );
+    let queries = ProfileQuery::default()
+        .for_platform(BundleIdPlatform::Ios)
+        .queries();
+    assert!(!queries[0].1.contains("MAC_APP_STORE"));
+    assert!(queries[0].1.contains("TVOS_APP_STORE"));
+
+    let queries = DeviceQuery::default().enabled_only().queries();
+    assert_eq!(
+        queries,
+        vec![("filter[status]".to_string(), "ENABLED".to_string())]
+    );
+}
+
+#[test]
+fn test_app_query() {
+    let queries = AppQuery::default()
+        .filter_bundle_id("com.example.app".to_string())
+        .filter_sku("EXAMPLE".to_string())
+        .filter_app_store_versions_app_store_state(AppStoreState::ReadyForSale)
+        .sort(AppSort::Name)
+        .queries();
+    assert_eq!(
+        queries,
+        vec![
+            (
+                "filter[bundleId]".to_string(),
+                "com.example.app".to_string()
+            ),
+            ("filter[sku]".to_string(), "EXAMPLE".to_string()),
+            (
+                "filter[appStoreVersions.appStoreState]".to_string(),
+                "READY_FOR_SALE".to_string()
+            ),
+            ("sort".to_string(), "name".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_cursor() {
+    let queries = DeviceQuery::default()
+        .limit(50)
+        .cursor("Mg".to_string())
+        .queries();
+    assert_eq!(
+        queries,
+        vec![
+            ("limit".to_string(), "50".to_string()),
+            ("cursor".to_string(), "Mg".to_string()),
+        ]
+    );
+
+    let page: PageResponse<App> = serde_json::from_value(serde_json::json!({
+        "data": [],
+        "links": {
+            "self": "https://api.appstoreconnect.apple.com/v1/apps",
+            "next": "https://api.appstoreconnect.apple.com/v1/apps?cursor=Mg.Aa%3D&limit=50",
+        },
+        "meta": { "paging": { "total": 100, "limit": 50 } },
+    }))
+    .unwrap();
+    assert_eq!(page.next_cursor().as_deref(), Some("Mg.Aa="));
+    let last = PageResponse::<App> {
+        links: PagedDocumentLinks::default(),
+        ..page
+    };
+    assert_eq!(last.next_cursor(), None);
+}
+
+#[test]
+fn test_file_name() {
+    use crate::entities::file_name;
+
+    assert_eq!(file_name("ci", "cer"), "ci.cer");
+    assert_eq!(
+        file_name("Apple Distribution: Team", "cer"),
+        "Apple Distribution_ Team.cer"
+    );
+    assert_eq!(file_name("../etc/passwd", "cer"), "_etc_passwd.cer");
+    assert_eq!(file_name(" ", "mobileprovision"), "unnamed.mobileprovision");
+}
+
+#[tokio::test]
+async fn test_run_buffered() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let running = AtomicUsize::new(0);
+    let most = AtomicUsize::new(0);
+    let tasks = (0..10).map(|index| {
+        let (running, most) = (&running, &most);
+        async move {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            most.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(10 - index)).await;
+            running.fetch_sub(1, Ordering::SeqCst);
+            index
+        }
+    });
+    let outputs = crate::client::run_buffered(tasks, 3).await;
+    assert_eq!(outputs, (0..10).collect::<Vec<u64>>());
+    assert_eq!(most.load(Ordering::SeqCst), 3);
+}